@@ -0,0 +1,305 @@
+use crate::{ring_buffer, RingBuffer};
+use std::{
+    borrow::Borrow,
+    fmt::{self, Debug, Formatter},
+    ops::Index,
+};
+
+pub struct BoundedMap<K, V>(RingBuffer<(K, V)>);
+
+impl<K, V> BoundedMap<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self(RingBuffer::new(capacity))
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get<Q: Eq + ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.0.into_iter().find_map(|(other_key, value)| {
+            if other_key.borrow() == key {
+                Some(value)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn contains_key<Q: Eq + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.get(key).is_some()
+    }
+
+    pub fn oldest(&self) -> Option<(&K, &V)> {
+        self.0.first().map(|(key, value)| (key, value))
+    }
+
+    pub fn iter(&self) -> BoundedMapIterator<'_, K, V> {
+        self.into_iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.into_iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.into_iter().map(|(_, value)| value)
+    }
+}
+
+impl<K: Clone + Eq, V: Clone> BoundedMap<K, V> {
+    pub fn insert(&self, key: K, value: V) -> Self {
+        if self.contains_key(&key) {
+            Self(self.remove(&key).0.push((key, value)))
+        } else {
+            Self(self.0.push((key, value)))
+        }
+    }
+
+    pub fn insert_iter(&self, iterator: impl IntoIterator<Item = (K, V)>) -> Self {
+        let mut map = self.clone();
+
+        for (key, value) in iterator {
+            map = map.insert(key, value);
+        }
+
+        map
+    }
+
+    pub fn remove<Q: Eq + ?Sized>(&self, key: &Q) -> Self
+    where
+        K: Borrow<Q>,
+    {
+        if self.contains_key(key) {
+            Self(
+                RingBuffer::new(self.capacity()).push_iter(
+                    self.0
+                        .into_iter()
+                        .filter(|(other, _)| other.borrow() != key)
+                        .cloned(),
+                ),
+            )
+        } else {
+            self.clone()
+        }
+    }
+
+    pub fn pop_oldest(&self) -> Self {
+        Self(self.0.pop())
+    }
+}
+
+impl<Q: Eq + ?Sized, K, V> Index<&Q> for BoundedMap<K, V>
+where
+    K: Borrow<Q>,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("existent key")
+    }
+}
+
+impl<K, V> Clone for BoundedMap<K, V> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for BoundedMap<K, V> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{{")?;
+
+        for (index, (key, value)) in self.into_iter().enumerate() {
+            write!(formatter, "{:?}: {:?}", key, value)?;
+
+            if index < self.len() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "}}")?;
+
+        Ok(())
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for BoundedMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for BoundedMap<K, V> {}
+
+pub struct BoundedMapIterator<'a, K, V>(ring_buffer::RingBufferIterator<'a, (K, V)>);
+
+impl<'a, K, V> IntoIterator for &'a BoundedMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = BoundedMapIterator<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BoundedMapIterator(self.0.into_iter())
+    }
+}
+
+impl<'a, K, V> Iterator for BoundedMapIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, value)| (key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        BoundedMap::<(), ()>::new(42);
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(BoundedMap::<(), ()>::new(2), BoundedMap::new(2));
+        assert_ne!(BoundedMap::new(2), BoundedMap::new(2).insert(1, 1));
+        assert_eq!(
+            BoundedMap::new(2).insert(1, 1),
+            BoundedMap::new(2).insert(1, 2).insert(1, 1)
+        );
+        assert_eq!(
+            BoundedMap::new(2).insert(1, 1).insert(2, 2).insert(3, 3),
+            BoundedMap::new(2).insert(2, 2).insert(3, 3)
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(BoundedMap::<(), ()>::new(2).len(), 0);
+        assert_eq!(BoundedMap::new(2).insert(1, 1).len(), 1);
+        assert_eq!(BoundedMap::new(2).insert(1, 1).insert(1, 2).len(), 1);
+        assert_eq!(
+            BoundedMap::new(2)
+                .insert_iter([(1, 1), (2, 2), (3, 3)])
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(BoundedMap::<(), ()>::new(2).is_empty());
+        assert!(!BoundedMap::new(2).insert(1, 1).is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let map = BoundedMap::new(2).insert(1, 2).insert(3, 4);
+
+        assert_eq!(map.get(&1), Some(&2));
+        assert_eq!(map.get(&3), Some(&4));
+        assert_eq!(map.get(&5), None);
+        assert_eq!(map[&1], 2);
+    }
+
+    #[test]
+    fn insert_evict() {
+        let map = BoundedMap::new(2).insert(1, 1).insert(2, 2);
+        let other = map.insert(3, 3);
+
+        assert!(map.contains_key(&1));
+        assert!(!other.contains_key(&1));
+        assert!(other.contains_key(&2));
+        assert!(other.contains_key(&3));
+    }
+
+    #[test]
+    fn insert_existing_key() {
+        let map = BoundedMap::new(2)
+            .insert(1, 1)
+            .insert(2, 2)
+            .insert(1, 3)
+            .insert(4, 4);
+
+        assert_eq!(map.get(&1), Some(&3));
+        assert_eq!(map.get(&2), None);
+        assert_eq!(map.get(&4), Some(&4));
+    }
+
+    #[test]
+    fn oldest() {
+        assert_eq!(BoundedMap::<(), ()>::new(2).oldest(), None);
+        assert_eq!(
+            BoundedMap::new(2)
+                .insert_iter([(1, 1), (2, 2), (3, 3)])
+                .oldest(),
+            Some((&2, &2))
+        );
+    }
+
+    #[test]
+    fn remove() {
+        let map = BoundedMap::new(3).insert_iter([(1, 1), (2, 2), (3, 3)]);
+
+        assert_eq!(
+            map.remove(&2),
+            BoundedMap::new(3).insert_iter([(1, 1), (3, 3)])
+        );
+        assert_eq!(map.remove(&4), map);
+    }
+
+    #[test]
+    fn pop_oldest() {
+        assert_eq!(
+            BoundedMap::new(3)
+                .insert_iter([(1, 1), (2, 2), (3, 3)])
+                .pop_oldest(),
+            BoundedMap::new(3).insert_iter([(2, 2), (3, 3)])
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            BoundedMap::new(2)
+                .insert_iter([(1, 1), (2, 2), (3, 3)])
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![(&2, &2), (&3, &3)]
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", BoundedMap::<(), ()>::new(2)), "{}");
+        assert_eq!(
+            format!("{:?}", BoundedMap::new(2).insert_iter([(1, 2), (3, 4)])),
+            "{1: 2, 3: 4}"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let map = BoundedMap::new(3).insert_iter([(1, 1), (2, 2)]);
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            (&map).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
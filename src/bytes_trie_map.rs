@@ -0,0 +1,391 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    ops::Index,
+    rc::Rc,
+};
+
+pub struct BytesTrieMap<V> {
+    root: Rc<Node<V>>,
+    len: usize,
+}
+
+struct Node<V> {
+    value: Option<Rc<V>>,
+    children: Vec<(u8, Rc<Node<V>>)>,
+}
+
+impl<V> BytesTrieMap<V> {
+    pub fn new() -> Self {
+        Self {
+            root: Node::default().into(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, key: &[u8]) -> Option<&V> {
+        self.root.find(key)?.value.as_deref()
+    }
+
+    pub fn contains_key(&self, key: &[u8]) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn insert(&self, key: &[u8], value: V) -> Self {
+        let (root, inserted) = self.root.insert(key, value.into());
+
+        Self {
+            root: root.into(),
+            len: self.len + inserted as usize,
+        }
+    }
+
+    pub fn insert_iter<K: AsRef<[u8]>>(&self, iterator: impl IntoIterator<Item = (K, V)>) -> Self {
+        let mut map = self.clone();
+
+        for (key, value) in iterator {
+            map = map.insert(key.as_ref(), value);
+        }
+
+        map
+    }
+
+    pub fn remove(&self, key: &[u8]) -> Self {
+        if let Some(root) = self.root.remove(key) {
+            Self {
+                root: root.unwrap_or_default().into(),
+                len: self.len - 1,
+            }
+        } else {
+            self.clone()
+        }
+    }
+
+    pub fn iter_prefix(&self, prefix: &[u8]) -> BytesTrieMapIterator<'_, V> {
+        BytesTrieMapIterator(
+            self.root
+                .find(prefix)
+                .map(|node| (prefix.to_vec(), node))
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    pub fn iter(&self) -> BytesTrieMapIterator<'_, V> {
+        self.into_iter()
+    }
+}
+
+impl<V> Node<V> {
+    fn find(&self, key: &[u8]) -> Option<&Self> {
+        if let Some((byte, key)) = key.split_first() {
+            self.child(*byte).ok()?.find(key)
+        } else {
+            Some(self)
+        }
+    }
+
+    fn child(&self, byte: u8) -> Result<&Self, usize> {
+        self.children
+            .binary_search_by_key(&byte, |(other, _)| *other)
+            .map(|index| self.children[index].1.as_ref())
+    }
+
+    fn insert(&self, key: &[u8], value: Rc<V>) -> (Self, bool) {
+        let mut node = self.clone();
+
+        if let Some((&byte, key)) = key.split_first() {
+            match self.child(byte) {
+                Ok(child) => {
+                    let (child, inserted) = child.insert(key, value);
+                    let index = node
+                        .children
+                        .binary_search_by_key(&byte, |(other, _)| *other)
+                        .expect("existent child");
+
+                    node.children[index].1 = child.into();
+
+                    (node, inserted)
+                }
+                Err(index) => {
+                    let (child, _) = Self::default().insert(key, value);
+
+                    node.children.insert(index, (byte, child.into()));
+
+                    (node, true)
+                }
+            }
+        } else {
+            let inserted = node.value.is_none();
+
+            node.value = Some(value);
+
+            (node, inserted)
+        }
+    }
+
+    // Returns `None` if the key does not exist and `Some(None)` if the node
+    // becomes empty after removal.
+    fn remove(&self, key: &[u8]) -> Option<Option<Self>> {
+        let mut node = self.clone();
+
+        if let Some((&byte, key)) = key.split_first() {
+            let index = self
+                .children
+                .binary_search_by_key(&byte, |(other, _)| *other)
+                .ok()?;
+
+            if let Some(child) = self.children[index].1.remove(key)? {
+                node.children[index].1 = child.into();
+            } else {
+                node.children.remove(index);
+            }
+        } else {
+            node.value.take()?;
+        }
+
+        Some(if node.value.is_none() && node.children.is_empty() {
+            None
+        } else {
+            Some(node)
+        })
+    }
+}
+
+impl<V> Index<&[u8]> for BytesTrieMap<V> {
+    type Output = V;
+
+    fn index(&self, key: &[u8]) -> &Self::Output {
+        self.get(key).expect("existent key")
+    }
+}
+
+impl<V> Clone for BytesTrieMap<V> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<V> Clone for Node<V> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl<V> Default for BytesTrieMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Default for Node<V> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            children: vec![],
+        }
+    }
+}
+
+impl<V: Debug> Debug for BytesTrieMap<V> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{{")?;
+
+        for (index, (key, value)) in self.into_iter().enumerate() {
+            write!(formatter, "{:?}: {:?}", key, value)?;
+
+            if index < self.len() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "}}")?;
+
+        Ok(())
+    }
+}
+
+impl<V: PartialEq> PartialEq for BytesTrieMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.into_iter().eq(other)
+    }
+}
+
+impl<V: Eq> Eq for BytesTrieMap<V> {}
+
+impl<K: AsRef<[u8]>, V> FromIterator<(K, V)> for BytesTrieMap<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iterator: I) -> Self {
+        Self::new().insert_iter(iterator)
+    }
+}
+
+pub struct BytesTrieMapIterator<'a, V>(Vec<(Vec<u8>, &'a Node<V>)>);
+
+impl<'a, V> IntoIterator for &'a BytesTrieMap<V> {
+    type Item = (Vec<u8>, &'a V);
+    type IntoIter = BytesTrieMapIterator<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BytesTrieMapIterator(vec![(vec![], &self.root)])
+    }
+}
+
+impl<'a, V> Iterator for BytesTrieMapIterator<'a, V> {
+    type Item = (Vec<u8>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (key, node) = self.0.pop()?;
+
+            for (byte, child) in node.children.iter().rev() {
+                let mut key = key.clone();
+                key.push(*byte);
+                self.0.push((key, child));
+            }
+
+            if let Some(value) = &node.value {
+                return Some((key, value));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        BytesTrieMap::<()>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(BytesTrieMap::<()>::new(), BytesTrieMap::new());
+        assert_ne!(BytesTrieMap::new(), BytesTrieMap::new().insert(b"foo", 1));
+        assert_eq!(
+            BytesTrieMap::new().insert(b"foo", 1),
+            BytesTrieMap::new().insert(b"foo", 2).insert(b"foo", 1)
+        );
+        assert_eq!(
+            BytesTrieMap::new().insert(b"foo", 1).insert(b"bar", 2),
+            BytesTrieMap::new().insert(b"bar", 2).insert(b"foo", 1)
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(BytesTrieMap::<()>::new().len(), 0);
+        assert_eq!(BytesTrieMap::new().insert(b"foo", 1).len(), 1);
+        assert_eq!(
+            BytesTrieMap::new()
+                .insert(b"foo", 1)
+                .insert(b"foo", 2)
+                .len(),
+            1
+        );
+        assert_eq!(
+            BytesTrieMap::new().insert(b"foo", 1).insert(b"fo", 2).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(BytesTrieMap::<()>::new().is_empty());
+        assert!(!BytesTrieMap::new().insert(b"", 1).is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let map = BytesTrieMap::new()
+            .insert(b"foo", 1)
+            .insert(b"fo", 2)
+            .insert(b"", 3);
+
+        assert_eq!(map.get(b"foo"), Some(&1));
+        assert_eq!(map.get(b"fo"), Some(&2));
+        assert_eq!(map.get(b""), Some(&3));
+        assert_eq!(map.get(b"f"), None);
+        assert_eq!(map.get(b"fooo"), None);
+        assert_eq!(map[b"foo".as_slice()], 1);
+    }
+
+    #[test]
+    fn insert_persistent() {
+        let map = BytesTrieMap::new().insert(b"foo", 1);
+        let other = map.insert(b"foo", 2).insert(b"bar", 3);
+
+        assert_eq!(map.get(b"foo"), Some(&1));
+        assert_eq!(map.get(b"bar"), None);
+        assert_eq!(other.get(b"foo"), Some(&2));
+        assert_eq!(other.get(b"bar"), Some(&3));
+    }
+
+    #[test]
+    fn remove() {
+        let map = BytesTrieMap::new().insert(b"foo", 1).insert(b"fo", 2);
+
+        assert_eq!(map.remove(b"foo"), BytesTrieMap::new().insert(b"fo", 2));
+        assert_eq!(map.remove(b"fo"), BytesTrieMap::new().insert(b"foo", 1));
+        assert_eq!(map.remove(b"f"), map);
+        assert_eq!(map.remove(b"bar"), map);
+        assert_eq!(map.remove(b"foo").remove(b"fo"), BytesTrieMap::new());
+        assert_eq!(map.get(b"foo"), Some(&1));
+    }
+
+    #[test]
+    fn iter_prefix() {
+        let map = BytesTrieMap::from_iter([("foo", 1), ("fob", 2), ("bar", 3), ("f", 4)]);
+
+        assert_eq!(
+            map.iter_prefix(b"fo").collect::<Vec<_>>(),
+            vec![(b"fob".to_vec(), &2), (b"foo".to_vec(), &1)]
+        );
+        assert_eq!(map.iter_prefix(b"f").count(), 3);
+        assert_eq!(map.iter_prefix(b"").count(), 4);
+        assert_eq!(map.iter_prefix(b"baz").count(), 0);
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            BytesTrieMap::from_iter([(vec![2], 1), (vec![1, 2], 2), (vec![1], 3)])
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![(vec![1], &3), (vec![1, 2], &2), (vec![2], &1)]
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", BytesTrieMap::<()>::new()), "{}");
+        assert_eq!(
+            format!("{:?}", BytesTrieMap::from_iter([([1], 2), ([3], 4)])),
+            "{[1]: 2, [3]: 4}"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let map = BytesTrieMap::from_iter([("foo", 1), ("bar", 2)]);
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            (&map).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
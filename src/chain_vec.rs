@@ -0,0 +1,259 @@
+use crate::List;
+use std::{
+    fmt::{self, Debug, Formatter},
+    iter::Rev,
+    ops::Index,
+    rc::Rc,
+    slice, vec,
+};
+
+pub struct ChainVec<T> {
+    head: Rc<Vec<T>>,
+    tail: List<T>,
+}
+
+impl<T> ChainVec<T> {
+    pub fn new(head: Vec<T>) -> Self {
+        Self {
+            head: head.into(),
+            tail: Default::default(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.head.len() + self.tail.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_empty() && self.tail.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.head.len() {
+            self.head.get(index)
+        } else if index < self.len() {
+            self.tail.into_iter().nth(self.len() - 1 - index)
+        } else {
+            None
+        }
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.tail.into_iter().next().or_else(|| self.head.last())
+    }
+
+    pub fn push(&self, value: T) -> Self {
+        Self {
+            head: self.head.clone(),
+            tail: self.tail.push_front(value),
+        }
+    }
+
+    pub fn push_iter(&self, iterator: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            head: self.head.clone(),
+            tail: self.tail.push_front_iter(iterator),
+        }
+    }
+
+    pub fn iter(&self) -> ChainVecIterator<'_, T> {
+        self.into_iter()
+    }
+}
+
+impl<T> Index<usize> for ChainVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index in bounds")
+    }
+}
+
+impl<T> Clone for ChainVec<T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            tail: self.tail.clone(),
+        }
+    }
+}
+
+impl<T> Default for ChainVec<T> {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<T: Debug> Debug for ChainVec<T> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "[")?;
+
+        for (index, value) in self.into_iter().enumerate() {
+            write!(formatter, "{:?}", value)?;
+
+            if index < self.len() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "]")?;
+
+        Ok(())
+    }
+}
+
+impl<T: PartialEq> PartialEq for ChainVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.into_iter().eq(other)
+    }
+}
+
+impl<T: Eq> Eq for ChainVec<T> {}
+
+impl<T> FromIterator<T> for ChainVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        Self::new(iterator.into_iter().collect())
+    }
+}
+
+pub struct ChainVecIterator<'a, T> {
+    head_iterator: slice::Iter<'a, T>,
+    tail_iterator: Rev<vec::IntoIter<&'a T>>,
+}
+
+impl<'a, T> IntoIterator for &'a ChainVec<T> {
+    type Item = &'a T;
+    type IntoIter = ChainVecIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ChainVecIterator {
+            head_iterator: self.head.iter(),
+            tail_iterator: self.tail.into_iter().collect::<Vec<_>>().into_iter().rev(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for ChainVecIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.head_iterator
+            .next()
+            .or_else(|| self.tail_iterator.next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        ChainVec::<()>::new(vec![]);
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(ChainVec::<()>::new(vec![]), ChainVec::new(vec![]));
+        assert_ne!(ChainVec::new(vec![]), ChainVec::new(vec![]).push(1));
+        assert_eq!(ChainVec::new(vec![1]), ChainVec::new(vec![]).push(1));
+        assert_eq!(
+            ChainVec::new(vec![1, 2]).push(3),
+            ChainVec::new(vec![1]).push(2).push(3)
+        );
+        assert_ne!(
+            ChainVec::new(vec![1, 2]).push(3),
+            ChainVec::new(vec![1]).push(3).push(2)
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(ChainVec::<()>::new(vec![]).len(), 0);
+        assert_eq!(ChainVec::new(vec![1]).len(), 1);
+        assert_eq!(ChainVec::new(vec![1]).push(2).len(), 2);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(ChainVec::<()>::new(vec![]).is_empty());
+        assert!(!ChainVec::new(vec![1]).is_empty());
+        assert!(!ChainVec::new(vec![]).push(1).is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let vector = ChainVec::new(vec![1, 2]).push(3).push(4);
+
+        assert_eq!(vector.get(0), Some(&1));
+        assert_eq!(vector.get(1), Some(&2));
+        assert_eq!(vector.get(2), Some(&3));
+        assert_eq!(vector.get(3), Some(&4));
+        assert_eq!(vector.get(4), None);
+    }
+
+    #[test]
+    fn index() {
+        assert_eq!(ChainVec::new(vec![1]).push(2)[1], 2);
+    }
+
+    #[test]
+    fn last() {
+        assert_eq!(ChainVec::<()>::new(vec![]).last(), None);
+        assert_eq!(ChainVec::new(vec![1]).last(), Some(&1));
+        assert_eq!(ChainVec::new(vec![1]).push(2).last(), Some(&2));
+    }
+
+    #[test]
+    fn push_persistent() {
+        let vector = ChainVec::new(vec![1]);
+        let other = vector.push(2);
+
+        assert_eq!(vector.len(), 1);
+        assert_eq!(other.len(), 2);
+    }
+
+    #[test]
+    fn push_iter() {
+        assert_eq!(
+            ChainVec::new(vec![1]).push(2).push(3),
+            ChainVec::new(vec![1]).push_iter([2, 3])
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            ChainVec::new(vec![1, 2])
+                .push_iter([3, 4])
+                .into_iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn from_iter() {
+        assert_eq!(ChainVec::from_iter([1, 2]), ChainVec::new(vec![1, 2]));
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", ChainVec::<()>::new(vec![])), "[]");
+        assert_eq!(
+            format!("{:?}", ChainVec::new(vec![1, 2]).push(3)),
+            "[1, 2, 3]"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let vector = ChainVec::new(vec![1, 2]).push(3);
+
+        assert_eq!(
+            vector.iter().collect::<Vec<_>>(),
+            (&vector).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
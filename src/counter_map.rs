@@ -0,0 +1,261 @@
+use crate::{map, Map};
+use std::{
+    borrow::Borrow,
+    cmp::Reverse,
+    fmt::{self, Debug, Formatter},
+    hash::Hash,
+};
+
+pub struct CounterMap<K> {
+    counts: Map<K, usize>,
+    total: usize,
+}
+
+impl<K> CounterMap<K> {
+    pub fn new() -> Self {
+        Self {
+            counts: Default::default(),
+            total: 0,
+        }
+    }
+
+    pub fn count<Q: Eq + ?Sized>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+    {
+        self.counts.get(key).copied().unwrap_or_default()
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+impl<K: Eq + Hash> CounterMap<K> {
+    pub fn increment(&self, key: K) -> Self {
+        let count = self.count(&key);
+
+        Self {
+            counts: self.counts.insert(key, count + 1),
+            total: self.total + 1,
+        }
+    }
+
+    pub fn decrement(&self, key: K) -> Self {
+        let count = self.count(&key);
+
+        if count == 0 {
+            self.clone()
+        } else {
+            Self {
+                counts: self.counts.insert(key, count - 1),
+                total: self.total - 1,
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.into_iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    pub fn iter(&self) -> CounterMapIterator<'_, K> {
+        self.into_iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.into_iter().map(|(key, _)| key)
+    }
+
+    pub fn most_common(&self, count: usize) -> Vec<(&K, usize)> {
+        let mut entries = self.into_iter().collect::<Vec<_>>();
+
+        entries.sort_by_key(|&(_, count)| Reverse(count));
+        entries.truncate(count);
+
+        entries
+    }
+}
+
+impl<K> Clone for CounterMap<K> {
+    fn clone(&self) -> Self {
+        Self {
+            counts: self.counts.clone(),
+            total: self.total,
+        }
+    }
+}
+
+impl<K> Default for CounterMap<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug + Eq + Hash> Debug for CounterMap<K> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{{")?;
+
+        for (index, (key, count)) in self.into_iter().enumerate() {
+            write!(formatter, "{:?}: {:?}", key, count)?;
+
+            if index < self.len() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "}}")?;
+
+        Ok(())
+    }
+}
+
+impl<K: Eq + Hash> PartialEq for CounterMap<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.total == other.total
+            && other
+                .into_iter()
+                .all(|(key, count)| self.count(key) == count)
+    }
+}
+
+impl<K: Eq + Hash> Eq for CounterMap<K> {}
+
+impl<K: Eq + Hash> FromIterator<K> for CounterMap<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iterator: I) -> Self {
+        let mut map = Self::new();
+
+        for key in iterator {
+            map = map.increment(key);
+        }
+
+        map
+    }
+}
+
+pub struct CounterMapIterator<'a, K: Eq + Hash>(map::MapIterator<'a, K, usize>);
+
+impl<'a, K: Eq + Hash> IntoIterator for &'a CounterMap<K> {
+    type Item = (&'a K, usize);
+    type IntoIter = CounterMapIterator<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CounterMapIterator(self.counts.into_iter())
+    }
+}
+
+impl<'a, K: Eq + Hash> Iterator for CounterMapIterator<'a, K> {
+    type Item = (&'a K, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .find(|(_, &count)| count != 0)
+            .map(|(key, &count)| (key, count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn new() {
+        CounterMap::<()>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(CounterMap::<()>::new(), CounterMap::new());
+        assert_ne!(CounterMap::new(), CounterMap::new().increment(1));
+        assert_eq!(
+            CounterMap::new().increment(1).increment(2),
+            CounterMap::new().increment(2).increment(1)
+        );
+        assert_eq!(
+            CounterMap::new(),
+            CounterMap::new().increment(1).decrement(1)
+        );
+    }
+
+    #[test]
+    fn count() {
+        let map = CounterMap::new().increment(1).increment(1).increment(2);
+
+        assert_eq!(map.count(&1), 2);
+        assert_eq!(map.count(&2), 1);
+        assert_eq!(map.count(&3), 0);
+    }
+
+    #[test]
+    fn decrement() {
+        let map = CounterMap::new().increment(1).increment(1);
+
+        assert_eq!(map.decrement(1).count(&1), 1);
+        assert_eq!(map.decrement(1).decrement(1).count(&1), 0);
+        assert_eq!(map.decrement(1).decrement(1).decrement(1).count(&1), 0);
+        assert_eq!(map.decrement(2).count(&2), 0);
+    }
+
+    #[test]
+    fn total() {
+        assert_eq!(CounterMap::<()>::new().total(), 0);
+        assert_eq!(CounterMap::new().increment(1).increment(2).total(), 2);
+        assert_eq!(CounterMap::new().increment(1).decrement(1).total(), 0);
+        assert_eq!(CounterMap::new().decrement(1).total(), 0);
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(CounterMap::<()>::new().len(), 0);
+        assert_eq!(CounterMap::new().increment(1).increment(1).len(), 1);
+        assert_eq!(CounterMap::new().increment(1).increment(2).len(), 2);
+        assert_eq!(CounterMap::new().increment(1).decrement(1).len(), 0);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(CounterMap::<()>::new().is_empty());
+        assert!(!CounterMap::new().increment(1).is_empty());
+        assert!(CounterMap::new().increment(1).decrement(1).is_empty());
+    }
+
+    #[test]
+    fn most_common() {
+        let map = CounterMap::from_iter([1, 2, 2, 3, 3, 3]);
+
+        assert_eq!(map.most_common(0), vec![]);
+        assert_eq!(map.most_common(2), vec![(&3, 3), (&2, 2)]);
+        assert_eq!(map.most_common(4), vec![(&3, 3), (&2, 2), (&1, 1)]);
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            CounterMap::from_iter([1, 2, 2, 3])
+                .decrement(3)
+                .into_iter()
+                .collect::<HashSet<_>>(),
+            [(&1, 1), (&2, 2)].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", CounterMap::<()>::new()), "{}");
+        assert_eq!(format!("{:?}", CounterMap::from_iter([1, 1])), "{1: 2}");
+    }
+
+    #[test]
+    fn iter() {
+        let map = CounterMap::from_iter([1, 2, 2]);
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            (&map).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{map, Map};
+use std::{
+    borrow::Borrow,
+    fmt::{self, Debug, Formatter},
+    hash::Hash,
+    rc::Rc,
+};
+
+pub struct DefaultMap<K, V> {
+    map: Map<K, V>,
+    default: Rc<dyn Fn() -> V>,
+}
+
+impl<K, V> DefaultMap<K, V> {
+    pub fn new(default: impl Fn() -> V + 'static) -> Self {
+        Self {
+            map: Default::default(),
+            default: Rc::new(default),
+        }
+    }
+
+    pub fn insert(&self, key: K, value: V) -> Self {
+        Self {
+            map: self.map.insert(key, value),
+            default: self.default.clone(),
+        }
+    }
+
+    pub fn insert_iter(&self, iterator: impl IntoIterator<Item = (K, V)>) -> Self {
+        Self {
+            map: self.map.insert_iter(iterator),
+            default: self.default.clone(),
+        }
+    }
+
+    pub fn as_map(&self) -> &Map<K, V> {
+        &self.map
+    }
+}
+
+impl<K: Eq + Hash, V> DefaultMap<K, V> {
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn contains_key<Q: Eq + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.map.contains_key(key)
+    }
+
+    pub fn iter(&self) -> DefaultMapIterator<'_, K, V> {
+        self.into_iter()
+    }
+}
+
+impl<K: Eq, V: Clone> DefaultMap<K, V> {
+    pub fn get<Q: Eq + ?Sized>(&self, key: &Q) -> V
+    where
+        K: Borrow<Q>,
+    {
+        self.map
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| (self.default)())
+    }
+
+    pub fn update(&self, key: K, function: impl FnOnce(V) -> V) -> Self {
+        let value = function(self.get(&key));
+
+        self.insert(key, value)
+    }
+}
+
+impl<K, V> Clone for DefaultMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+            default: self.default.clone(),
+        }
+    }
+}
+
+impl<K, V: Default + 'static> Default for DefaultMap<K, V> {
+    fn default() -> Self {
+        Self::new(V::default)
+    }
+}
+
+impl<K: Debug + Eq + Hash, V: Debug> Debug for DefaultMap<K, V> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self.map)
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq> PartialEq for DefaultMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K: Eq + Hash, V: Eq> Eq for DefaultMap<K, V> {}
+
+pub struct DefaultMapIterator<'a, K: Eq + Hash, V>(map::MapIterator<'a, K, V>);
+
+impl<'a, K: Eq + Hash, V> IntoIterator for &'a DefaultMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = DefaultMapIterator<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DefaultMapIterator(self.map.iter())
+    }
+}
+
+impl<'a, K: Eq + Hash, V> Iterator for DefaultMapIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        DefaultMap::<(), ()>::new(|| ());
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(DefaultMap::<usize, usize>::default(), DefaultMap::new(|| 1));
+        assert_eq!(
+            DefaultMap::<_, usize>::default().insert(1, 1),
+            DefaultMap::default().insert(1, 1)
+        );
+        assert_ne!(
+            DefaultMap::<_, usize>::default().insert(1, 1),
+            DefaultMap::default().insert(1, 2)
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(DefaultMap::<usize, usize>::default().len(), 0);
+        assert_eq!(DefaultMap::<_, usize>::default().insert(1, 1).len(), 1);
+        assert!(DefaultMap::<usize, usize>::default().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let map = DefaultMap::new(|| 42).insert(1, 1);
+
+        assert_eq!(map.get(&1), 1);
+        assert_eq!(map.get(&2), 42);
+        assert!(map.contains_key(&1));
+        assert!(!map.contains_key(&2));
+    }
+
+    #[test]
+    fn update() {
+        let map = ["foo", "bar", "foo"]
+            .into_iter()
+            .fold(DefaultMap::<_, usize>::default(), |map, word| {
+                map.update(word, |count| count + 1)
+            });
+
+        assert_eq!(map.get("foo"), 2);
+        assert_eq!(map.get("bar"), 1);
+        assert_eq!(map.get("baz"), 0);
+    }
+
+    #[test]
+    fn as_map() {
+        assert_eq!(
+            DefaultMap::<_, usize>::default().insert(1, 1).as_map(),
+            &Map::new().insert(1, 1)
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", DefaultMap::<_, usize>::default().insert(1, 2)),
+            "{1: 2}"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let map = DefaultMap::<_, usize>::default().insert_iter([(1, 1), (2, 2)]);
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            (&map).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{list, List};
+use std::{
+    fmt::{self, Debug, Formatter},
+    ops::Index,
+};
+
+pub struct Env<T>(List<T>);
+
+impl<T> Env<T> {
+    pub fn new() -> Self {
+        Self(Default::default())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn push(&self, value: T) -> Self {
+        Self(self.0.push_front(value))
+    }
+
+    pub fn lookup(&self, index: usize) -> Option<&T> {
+        self.0.get(index)
+    }
+
+    pub fn shift(&self, count: usize) -> Self {
+        Self(self.0.suffix_at(count))
+    }
+
+    pub fn iter(&self) -> EnvIterator<'_, T> {
+        self.into_iter()
+    }
+}
+
+impl<T: Clone> Env<T> {
+    pub fn subst(&self, index: usize, value: T) -> Option<Self> {
+        Some(Self(self.0.update_at(index, value)?))
+    }
+}
+
+impl<T> Index<usize> for Env<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.lookup(index).expect("index in bounds")
+    }
+}
+
+impl<T> Clone for Env<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Default for Env<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for Env<T> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self.0)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Env<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq> Eq for Env<T> {}
+
+pub struct EnvIterator<'a, T>(list::ListIterator<'a, T>);
+
+impl<'a, T> IntoIterator for &'a Env<T> {
+    type Item = &'a T;
+    type IntoIter = EnvIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        EnvIterator(self.0.iter())
+    }
+}
+
+impl<'a, T> Iterator for EnvIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        Env::<()>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(Env::<()>::new(), Env::new());
+        assert_ne!(Env::new(), Env::new().push(1));
+        assert_eq!(Env::new().push(1).push(2), Env::new().push(1).push(2));
+        assert_ne!(Env::new().push(1).push(2), Env::new().push(2).push(1));
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Env::<()>::new().len(), 0);
+        assert_eq!(Env::new().push(1).len(), 1);
+        assert_eq!(Env::new().push(1).push(2).len(), 2);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(Env::<()>::new().is_empty());
+        assert!(!Env::new().push(1).is_empty());
+    }
+
+    #[test]
+    fn lookup() {
+        let env = Env::new().push(1).push(2).push(3);
+
+        assert_eq!(env.lookup(0), Some(&3));
+        assert_eq!(env.lookup(1), Some(&2));
+        assert_eq!(env.lookup(2), Some(&1));
+        assert_eq!(env.lookup(3), None);
+        assert_eq!(env[0], 3);
+    }
+
+    #[test]
+    fn shift() {
+        let env = Env::new().push(1).push(2).push(3);
+
+        assert_eq!(env.shift(0), env);
+        assert_eq!(env.shift(1), Env::new().push(1).push(2));
+        assert_eq!(env.shift(3), Env::new());
+        assert_eq!(env.shift(4), Env::new());
+    }
+
+    #[test]
+    fn subst() {
+        let env = Env::new().push(1).push(2).push(3);
+
+        assert_eq!(env.subst(0, 4), Some(Env::new().push(1).push(2).push(4)));
+        assert_eq!(env.subst(2, 4), Some(Env::new().push(4).push(2).push(3)));
+        assert_eq!(env.subst(3, 4), None);
+        assert_eq!(env.lookup(0), Some(&3));
+    }
+
+    #[test]
+    fn subst_shares_suffix() {
+        let env = Env::new().push(1).push(2).push(3);
+        let other = env.subst(1, 4).unwrap();
+
+        assert!(std::ptr::eq(
+            other.lookup(2).unwrap(),
+            env.lookup(2).unwrap()
+        ));
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", Env::<()>::new()), "[]");
+        assert_eq!(format!("{:?}", Env::new().push(1).push(2)), "[2, 1]");
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            Env::new().push(1).push(2).into_iter().collect::<Vec<_>>(),
+            vec![&2, &1]
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let env = Env::new().push(1).push(2);
+
+        assert_eq!(
+            env.iter().collect::<Vec<_>>(),
+            (&env).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,755 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    rc::Rc,
+};
+
+pub trait Measure<T> {
+    type Output: Clone;
+
+    fn identity() -> Self::Output;
+    fn measure(value: &T) -> Self::Output;
+    fn combine(one: &Self::Output, other: &Self::Output) -> Self::Output;
+}
+
+pub struct Size;
+
+impl<T> Measure<T> for Size {
+    type Output = usize;
+
+    fn identity() -> usize {
+        0
+    }
+
+    fn measure(_: &T) -> usize {
+        1
+    }
+
+    fn combine(one: &usize, other: &usize) -> usize {
+        one + other
+    }
+}
+
+pub struct FingerTree<T, M: Measure<T>>(Rc<Tree<T, M>>);
+
+type Digit<T, M> = Vec<Rc<Node<T, M>>>;
+type Split<T, M, P> = (P, Rc<Node<T, M>>, P);
+
+enum Tree<T, M: Measure<T>> {
+    Empty,
+    Single(Rc<Node<T, M>>),
+    Deep {
+        measure: M::Output,
+        prefix: Digit<T, M>,
+        middle: Rc<Tree<T, M>>,
+        suffix: Digit<T, M>,
+    },
+}
+
+enum Node<T, M: Measure<T>> {
+    Leaf(T),
+    Branch(M::Output, Digit<T, M>),
+}
+
+impl<T, M: Measure<T>> FingerTree<T, M> {
+    pub fn new() -> Self {
+        Self(Tree::Empty.into())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self.0.as_ref(), Tree::Empty)
+    }
+
+    pub fn measure(&self) -> M::Output {
+        self.0.measure()
+    }
+
+    pub fn push_front(&self, value: T) -> Self {
+        Self(self.0.push_front(Node::Leaf(value).into()).into())
+    }
+
+    pub fn push_back(&self, value: T) -> Self {
+        Self(self.0.push_back(Node::Leaf(value).into()).into())
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        match self.0.as_ref() {
+            Tree::Empty => None,
+            Tree::Single(node) => Some(node.first()),
+            Tree::Deep { prefix, .. } => Some(prefix[0].first()),
+        }
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        match self.0.as_ref() {
+            Tree::Empty => None,
+            Tree::Single(node) => Some(node.last()),
+            Tree::Deep { suffix, .. } => Some(suffix[suffix.len() - 1].last()),
+        }
+    }
+
+    pub fn pop_front(&self) -> Self {
+        if let Some((_, tree)) = self.0.view_front() {
+            Self(tree.into())
+        } else {
+            Self::new()
+        }
+    }
+
+    pub fn pop_back(&self) -> Self {
+        if let Some((tree, _)) = self.0.view_back() {
+            Self(tree.into())
+        } else {
+            Self::new()
+        }
+    }
+
+    pub fn concat(&self, other: &Self) -> Self {
+        Self(Tree::concat(&self.0, vec![], &other.0).into())
+    }
+
+    pub fn split(&self, predicate: impl Fn(&M::Output) -> bool) -> (Self, Self) {
+        if self.is_empty() || !predicate(&self.measure()) {
+            (self.clone(), Self::new())
+        } else {
+            let (left, node, right) = self.0.split(&predicate, &M::identity());
+
+            (Self(left.into()), Self(right.push_front(node).into()))
+        }
+    }
+
+    pub fn iter(&self) -> FingerTreeIterator<'_, T, M> {
+        self.into_iter()
+    }
+}
+
+impl<T, M: Measure<T>> Clone for FingerTree<T, M> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T, M: Measure<T>> Default for FingerTree<T, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, M: Measure<T>> Debug for FingerTree<T, M> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "[")?;
+
+        for (index, value) in self.into_iter().enumerate() {
+            if index > 0 {
+                write!(formatter, ", ")?;
+            }
+
+            write!(formatter, "{:?}", value)?;
+        }
+
+        write!(formatter, "]")?;
+
+        Ok(())
+    }
+}
+
+impl<T: PartialEq, M: Measure<T>> PartialEq for FingerTree<T, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.into_iter().eq(other)
+    }
+}
+
+impl<T: Eq, M: Measure<T>> Eq for FingerTree<T, M> {}
+
+impl<T, M: Measure<T>> FromIterator<T> for FingerTree<T, M> {
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let mut tree = Tree::Empty;
+
+        for value in iterator {
+            tree = tree.push_back(Node::Leaf(value).into());
+        }
+
+        Self(tree.into())
+    }
+}
+
+impl<T, M: Measure<T>> Tree<T, M> {
+    fn measure(&self) -> M::Output {
+        match self {
+            Self::Empty => M::identity(),
+            Self::Single(node) => node.measure(),
+            Self::Deep { measure, .. } => measure.clone(),
+        }
+    }
+
+    fn deep(prefix: Digit<T, M>, middle: Rc<Self>, suffix: Digit<T, M>) -> Self {
+        Self::Deep {
+            measure: M::combine(
+                &M::combine(&measure_digit(&prefix), &middle.measure()),
+                &measure_digit(&suffix),
+            ),
+            prefix,
+            middle,
+            suffix,
+        }
+    }
+
+    fn from_digit(digit: &[Rc<Node<T, M>>]) -> Self {
+        digit
+            .iter()
+            .fold(Self::Empty, |tree, node| tree.push_back(node.clone()))
+    }
+
+    fn push_front(&self, node: Rc<Node<T, M>>) -> Self {
+        match self {
+            Self::Empty => Self::Single(node),
+            Self::Single(other) => Self::deep(vec![node], Self::Empty.into(), vec![other.clone()]),
+            Self::Deep {
+                prefix,
+                middle,
+                suffix,
+                ..
+            } => {
+                if prefix.len() == 4 {
+                    Self::deep(
+                        vec![node, prefix[0].clone()],
+                        middle.push_front(Node::branch(prefix[1..].to_vec())).into(),
+                        suffix.clone(),
+                    )
+                } else {
+                    Self::deep(
+                        [node].into_iter().chain(prefix.iter().cloned()).collect(),
+                        middle.clone(),
+                        suffix.clone(),
+                    )
+                }
+            }
+        }
+    }
+
+    fn push_back(&self, node: Rc<Node<T, M>>) -> Self {
+        match self {
+            Self::Empty => Self::Single(node),
+            Self::Single(other) => Self::deep(vec![other.clone()], Self::Empty.into(), vec![node]),
+            Self::Deep {
+                prefix,
+                middle,
+                suffix,
+                ..
+            } => {
+                if suffix.len() == 4 {
+                    Self::deep(
+                        prefix.clone(),
+                        middle.push_back(Node::branch(suffix[..3].to_vec())).into(),
+                        vec![suffix[3].clone(), node],
+                    )
+                } else {
+                    Self::deep(
+                        prefix.clone(),
+                        middle.clone(),
+                        suffix.iter().cloned().chain([node]).collect(),
+                    )
+                }
+            }
+        }
+    }
+
+    fn view_front(&self) -> Option<(Rc<Node<T, M>>, Self)> {
+        match self {
+            Self::Empty => None,
+            Self::Single(node) => Some((node.clone(), Self::Empty)),
+            Self::Deep {
+                prefix,
+                middle,
+                suffix,
+                ..
+            } => Some((
+                prefix[0].clone(),
+                Self::deep_left(prefix[1..].to_vec(), middle, suffix.clone()),
+            )),
+        }
+    }
+
+    fn view_back(&self) -> Option<(Self, Rc<Node<T, M>>)> {
+        match self {
+            Self::Empty => None,
+            Self::Single(node) => Some((Self::Empty, node.clone())),
+            Self::Deep {
+                prefix,
+                middle,
+                suffix,
+                ..
+            } => Some((
+                Self::deep_right(prefix.clone(), middle, suffix[..suffix.len() - 1].to_vec()),
+                suffix[suffix.len() - 1].clone(),
+            )),
+        }
+    }
+
+    fn deep_left(prefix: Digit<T, M>, middle: &Rc<Self>, suffix: Digit<T, M>) -> Self {
+        if !prefix.is_empty() {
+            Self::deep(prefix, middle.clone(), suffix)
+        } else if let Some((node, middle)) = middle.view_front() {
+            Self::deep(node.children().to_vec(), middle.into(), suffix)
+        } else {
+            Self::from_digit(&suffix)
+        }
+    }
+
+    fn deep_right(prefix: Digit<T, M>, middle: &Rc<Self>, suffix: Digit<T, M>) -> Self {
+        if !suffix.is_empty() {
+            Self::deep(prefix, middle.clone(), suffix)
+        } else if let Some((middle, node)) = middle.view_back() {
+            Self::deep(prefix, middle.into(), node.children().to_vec())
+        } else {
+            Self::from_digit(&prefix)
+        }
+    }
+
+    fn concat(left: &Self, nodes: Digit<T, M>, right: &Self) -> Self {
+        match (left, right) {
+            (Self::Empty, _) => nodes
+                .into_iter()
+                .rev()
+                .fold(right.clone(), |tree, node| tree.push_front(node)),
+            (_, Self::Empty) => nodes
+                .into_iter()
+                .fold(left.clone(), |tree, node| tree.push_back(node)),
+            (Self::Single(node), _) => {
+                Self::concat(&Self::Empty, nodes, right).push_front(node.clone())
+            }
+            (_, Self::Single(node)) => {
+                Self::concat(left, nodes, &Self::Empty).push_back(node.clone())
+            }
+            (
+                Self::Deep {
+                    prefix,
+                    middle: left_middle,
+                    suffix: left_suffix,
+                    ..
+                },
+                Self::Deep {
+                    prefix: right_prefix,
+                    middle: right_middle,
+                    suffix,
+                    ..
+                },
+            ) => Self::deep(
+                prefix.clone(),
+                Self::concat(
+                    left_middle,
+                    Node::branches(
+                        &left_suffix
+                            .iter()
+                            .cloned()
+                            .chain(nodes)
+                            .chain(right_prefix.iter().cloned())
+                            .collect::<Vec<_>>(),
+                    ),
+                    right_middle,
+                )
+                .into(),
+                suffix.clone(),
+            ),
+        }
+    }
+
+    fn split(
+        &self,
+        predicate: &impl Fn(&M::Output) -> bool,
+        initial: &M::Output,
+    ) -> Split<T, M, Self> {
+        match self {
+            Self::Empty => unreachable!("non-empty tree"),
+            Self::Single(node) => (Self::Empty, node.clone(), Self::Empty),
+            Self::Deep {
+                prefix,
+                middle,
+                suffix,
+                ..
+            } => {
+                let prefix_measure = M::combine(initial, &measure_digit(prefix));
+
+                if predicate(&prefix_measure) {
+                    let (left, node, right) = split_digit(predicate, initial, prefix);
+
+                    (
+                        Self::from_digit(&left),
+                        node,
+                        Self::deep_left(right, middle, suffix.clone()),
+                    )
+                } else {
+                    let middle_measure = M::combine(&prefix_measure, &middle.measure());
+
+                    if predicate(&middle_measure) {
+                        let (middle_left, branch, middle_right) =
+                            middle.split(predicate, &prefix_measure);
+                        let (left, node, right) = split_digit(
+                            predicate,
+                            &M::combine(&prefix_measure, &middle_left.measure()),
+                            branch.children(),
+                        );
+
+                        (
+                            Self::deep_right(prefix.clone(), &middle_left.into(), left),
+                            node,
+                            Self::deep_left(right, &middle_right.into(), suffix.clone()),
+                        )
+                    } else {
+                        let (left, node, right) = split_digit(predicate, &middle_measure, suffix);
+
+                        (
+                            Self::deep_right(prefix.clone(), middle, left),
+                            node,
+                            Self::from_digit(&right),
+                        )
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T, M: Measure<T>> Clone for Tree<T, M> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Empty => Self::Empty,
+            Self::Single(node) => Self::Single(node.clone()),
+            Self::Deep {
+                measure,
+                prefix,
+                middle,
+                suffix,
+            } => Self::Deep {
+                measure: measure.clone(),
+                prefix: prefix.clone(),
+                middle: middle.clone(),
+                suffix: suffix.clone(),
+            },
+        }
+    }
+}
+
+impl<T, M: Measure<T>> Node<T, M> {
+    fn branch(children: Digit<T, M>) -> Rc<Self> {
+        Self::Branch(measure_digit(&children), children).into()
+    }
+
+    fn branches(mut nodes: &[Rc<Self>]) -> Digit<T, M> {
+        let mut branches = vec![];
+
+        loop {
+            match nodes.len() {
+                2 | 3 => {
+                    branches.push(Self::branch(nodes.to_vec()));
+                    break;
+                }
+                4 => {
+                    branches.push(Self::branch(nodes[..2].to_vec()));
+                    branches.push(Self::branch(nodes[2..].to_vec()));
+                    break;
+                }
+                _ => {
+                    branches.push(Self::branch(nodes[..3].to_vec()));
+                    nodes = &nodes[3..];
+                }
+            }
+        }
+
+        branches
+    }
+
+    fn measure(&self) -> M::Output {
+        match self {
+            Self::Leaf(value) => M::measure(value),
+            Self::Branch(measure, _) => measure.clone(),
+        }
+    }
+
+    fn children(&self) -> &[Rc<Self>] {
+        match self {
+            Self::Leaf(_) => &[],
+            Self::Branch(_, children) => children,
+        }
+    }
+
+    fn first(&self) -> &T {
+        match self {
+            Self::Leaf(value) => value,
+            Self::Branch(_, children) => children[0].first(),
+        }
+    }
+
+    fn last(&self) -> &T {
+        match self {
+            Self::Leaf(value) => value,
+            Self::Branch(_, children) => children[children.len() - 1].last(),
+        }
+    }
+}
+
+fn measure_digit<T, M: Measure<T>>(digit: &[Rc<Node<T, M>>]) -> M::Output {
+    digit.iter().fold(M::identity(), |measure, node| {
+        M::combine(&measure, &node.measure())
+    })
+}
+
+fn split_digit<T, M: Measure<T>>(
+    predicate: &impl Fn(&M::Output) -> bool,
+    initial: &M::Output,
+    digit: &[Rc<Node<T, M>>],
+) -> Split<T, M, Digit<T, M>> {
+    let mut measure = initial.clone();
+
+    for (index, node) in digit.iter().enumerate() {
+        measure = M::combine(&measure, &node.measure());
+
+        if predicate(&measure) || index == digit.len() - 1 {
+            return (
+                digit[..index].to_vec(),
+                node.clone(),
+                digit[index + 1..].to_vec(),
+            );
+        }
+    }
+
+    unreachable!("non-empty digit")
+}
+
+pub struct FingerTreeIterator<'a, T, M: Measure<T>>(Vec<Item<'a, T, M>>);
+
+enum Item<'a, T, M: Measure<T>> {
+    Tree(&'a Tree<T, M>),
+    Node(&'a Node<T, M>),
+}
+
+impl<'a, T, M: Measure<T>> IntoIterator for &'a FingerTree<T, M> {
+    type Item = &'a T;
+    type IntoIter = FingerTreeIterator<'a, T, M>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FingerTreeIterator(vec![Item::Tree(&self.0)])
+    }
+}
+
+impl<'a, T, M: Measure<T>> Iterator for FingerTreeIterator<'a, T, M> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.0.pop()? {
+                Item::Tree(Tree::Empty) => {}
+                Item::Tree(Tree::Single(node)) => self.0.push(Item::Node(node)),
+                Item::Tree(Tree::Deep {
+                    prefix,
+                    middle,
+                    suffix,
+                    ..
+                }) => {
+                    self.0
+                        .extend(suffix.iter().rev().map(|node| Item::Node(node)));
+                    self.0.push(Item::Tree(middle));
+                    self.0
+                        .extend(prefix.iter().rev().map(|node| Item::Node(node)));
+                }
+                Item::Node(Node::Leaf(value)) => return Some(value),
+                Item::Node(Node::Branch(_, children)) => {
+                    self.0
+                        .extend(children.iter().rev().map(|node| Item::Node(node)));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Max;
+
+    impl Measure<usize> for Max {
+        type Output = usize;
+
+        fn identity() -> usize {
+            0
+        }
+
+        fn measure(value: &usize) -> usize {
+            *value
+        }
+
+        fn combine(one: &usize, other: &usize) -> usize {
+            *one.max(other)
+        }
+    }
+
+    fn to_vec<T: Clone, M: Measure<T>>(tree: &FingerTree<T, M>) -> Vec<T> {
+        tree.into_iter().cloned().collect()
+    }
+
+    #[test]
+    fn new() {
+        FingerTree::<(), Size>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(FingerTree::<(), Size>::new(), FingerTree::new());
+        assert_ne!(FingerTree::<_, Size>::new(), FingerTree::new().push_back(1));
+        assert_eq!(
+            FingerTree::<_, Size>::new().push_back(1).push_back(2),
+            FingerTree::new().push_front(2).push_front(1)
+        );
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(FingerTree::<(), Size>::new().is_empty());
+        assert!(!FingerTree::<_, Size>::new().push_back(1).is_empty());
+        assert!(FingerTree::<_, Size>::new()
+            .push_back(1)
+            .pop_front()
+            .is_empty());
+    }
+
+    #[test]
+    fn measure() {
+        assert_eq!(FingerTree::<(), Size>::new().measure(), 0);
+        assert_eq!(FingerTree::<_, Size>::from_iter(0..100).measure(), 100);
+        assert_eq!(FingerTree::<_, Max>::from_iter([3, 7, 2]).measure(), 7);
+    }
+
+    #[test]
+    fn push_front() {
+        assert_eq!(
+            to_vec(&(0..100).fold(FingerTree::<_, Size>::new(), |tree, value| {
+                tree.push_front(value)
+            })),
+            (0..100).rev().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn push_back() {
+        assert_eq!(
+            to_vec(&(0..100).fold(FingerTree::<_, Size>::new(), |tree, value| {
+                tree.push_back(value)
+            })),
+            (0..100).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn front() {
+        assert_eq!(FingerTree::<(), Size>::new().front(), None);
+        assert_eq!(FingerTree::<_, Size>::from_iter(0..100).front(), Some(&0));
+    }
+
+    #[test]
+    fn back() {
+        assert_eq!(FingerTree::<(), Size>::new().back(), None);
+        assert_eq!(FingerTree::<_, Size>::from_iter(0..100).back(), Some(&99));
+    }
+
+    #[test]
+    fn pop_front() {
+        let mut tree = FingerTree::<_, Size>::from_iter(0..100);
+
+        for value in 0..100 {
+            assert_eq!(tree.front(), Some(&value));
+            assert_eq!(tree.measure(), 100 - value);
+            tree = tree.pop_front();
+        }
+
+        assert!(tree.is_empty());
+        assert!(tree.pop_front().is_empty());
+    }
+
+    #[test]
+    fn pop_back() {
+        let mut tree = FingerTree::<_, Size>::from_iter(0..100);
+
+        for value in (0..100).rev() {
+            assert_eq!(tree.back(), Some(&value));
+            tree = tree.pop_back();
+        }
+
+        assert!(tree.is_empty());
+        assert!(tree.pop_back().is_empty());
+    }
+
+    #[test]
+    fn concat() {
+        for left in 0..20 {
+            for right in 0..20 {
+                let tree = FingerTree::<_, Size>::from_iter(0..left)
+                    .concat(&FingerTree::from_iter(left..left + right));
+
+                assert_eq!(to_vec(&tree), (0..left + right).collect::<Vec<_>>());
+                assert_eq!(tree.measure(), left + right);
+            }
+        }
+    }
+
+    #[test]
+    fn split() {
+        for size in 0..50 {
+            let tree = FingerTree::<_, Size>::from_iter(0..size);
+
+            for index in 0..size + 2 {
+                let (left, right) = tree.split(|&size| size > index);
+
+                assert_eq!(to_vec(&left), (0..index.min(size)).collect::<Vec<_>>());
+                assert_eq!(to_vec(&right), (index.min(size)..size).collect::<Vec<_>>());
+                assert_eq!(left.measure(), index.min(size));
+                assert_eq!(right.measure(), size - index.min(size));
+            }
+        }
+    }
+
+    #[test]
+    fn split_concat() {
+        let tree = FingerTree::<_, Size>::from_iter(0..1000);
+        let (left, right) = tree.split(|&size| size > 333);
+        let (middle, right) = right.split(|&size| size > 333);
+
+        assert_eq!(left.concat(&middle).concat(&right), tree);
+    }
+
+    #[test]
+    fn split_max() {
+        let tree = FingerTree::<_, Max>::from_iter([3, 1, 4, 1, 5, 9, 2, 6]);
+        let (left, right) = tree.split(|&max| max >= 5);
+
+        assert_eq!(to_vec(&left), vec![3, 1, 4, 1]);
+        assert_eq!(to_vec(&right), vec![5, 9, 2, 6]);
+    }
+
+    #[test]
+    fn persistent() {
+        let tree = FingerTree::<_, Size>::from_iter(0..10);
+        let other = tree.push_back(10).pop_front();
+
+        assert_eq!(to_vec(&tree), (0..10).collect::<Vec<_>>());
+        assert_eq!(to_vec(&other), (1..11).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", FingerTree::<(), Size>::new()), "[]");
+        assert_eq!(
+            format!("{:?}", FingerTree::<_, Size>::from_iter([1, 2, 3])),
+            "[1, 2, 3]"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let tree = FingerTree::<_, Size>::from_iter([1, 2, 3]);
+
+        assert_eq!(
+            tree.iter().collect::<Vec<_>>(),
+            (&tree).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -1,23 +1,33 @@
-use crate::{map, Map};
+use crate::{map, Lookup, Map};
 use std::{
     borrow::Borrow,
-    collections::{hash_map, HashMap, HashSet},
+    collections::{btree_map, BTreeMap, HashMap, HashSet},
     fmt::{self, Debug, Formatter},
     hash::Hash,
+    iter::Peekable,
     ops::Index,
     rc::Rc,
+    vec,
 };
 
-pub struct FlailMap<K, V> {
+pub struct FlailMap<K, V, H = HashMap<K, V>> {
     chain: Map<K, V>,
-    head: Rc<HashMap<K, V>>,
+    head: Rc<H>,
+    parent: Option<Rc<FlailMap<K, V, H>>>,
 }
 
 impl<K, V> FlailMap<K, V> {
     pub fn new(head: HashMap<K, V>) -> Self {
+        Self::with_head(head)
+    }
+}
+
+impl<K, V, H> FlailMap<K, V, H> {
+    pub fn with_head(head: H) -> Self {
         Self {
             chain: Default::default(),
             head: head.into(),
+            parent: None,
         }
     }
 
@@ -25,6 +35,7 @@ impl<K, V> FlailMap<K, V> {
         Self {
             chain: self.chain.insert(key, value),
             head: self.head.clone(),
+            parent: self.parent.clone(),
         }
     }
 
@@ -32,37 +43,117 @@ impl<K, V> FlailMap<K, V> {
         Self {
             chain: self.chain.insert_iter(iterator),
             head: self.head.clone(),
+            parent: self.parent.clone(),
+        }
+    }
+
+    pub fn head(&self) -> &H {
+        &self.head
+    }
+
+    pub fn chain(&self) -> &Map<K, V> {
+        &self.chain
+    }
+
+    pub fn rebase(&self, head: H) -> Self {
+        Self {
+            chain: self.chain.clone(),
+            head: head.into(),
+            parent: self.parent.clone(),
         }
     }
+
+    pub fn push_layer(&self, layer: H) -> Self {
+        Self {
+            chain: Default::default(),
+            head: layer.into(),
+            parent: Some(self.clone().into()),
+        }
+    }
+
+    pub fn pop_layer(&self) -> Option<Self> {
+        self.parent.as_deref().cloned()
+    }
+
+    pub fn depth(&self) -> usize {
+        self.parent
+            .as_ref()
+            .map(|parent| parent.depth() + 1)
+            .unwrap_or_default()
+    }
 }
 
-impl<K: Eq + Hash, V> FlailMap<K, V> {
-    pub fn len(&self) -> usize {
-        let mut set = HashSet::new();
+impl<K, V> FlailMap<K, V, BTreeMap<K, V>> {
+    pub fn new_ordered(head: BTreeMap<K, V>) -> Self {
+        Self::with_head(head)
+    }
+}
 
-        for key in self.keys() {
-            set.insert(key);
+impl<K, V, H: Default> FlailMap<K, V, H> {
+    pub fn new_over(parent: Self) -> Self {
+        Self {
+            chain: Default::default(),
+            head: Default::default(),
+            parent: Some(parent.into()),
         }
+    }
+}
 
-        set.len()
+impl<K: Eq + Hash, V, H: Lookup<K, V>> FlailMap<K, V, H> {
+    pub fn len(&self) -> usize {
+        self.iter().count()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.chain.is_empty() && self.head.is_empty()
+        self.chain.is_empty()
+            && self.head.is_empty()
+            && self.parent.iter().all(|parent| parent.is_empty())
     }
 
-    pub fn get<Q: Eq + Hash + ?Sized>(&self, key: &Q) -> Option<&V>
+    pub fn get<Q: Eq + ?Sized>(&self, key: &Q) -> Option<&V>
     where
         K: Borrow<Q>,
+        H: Lookup<K, V, Q>,
     {
-        self.chain.get(key).or_else(|| self.head.get(key))
+        self.chain
+            .get(key)
+            .or_else(|| self.head.get(key))
+            .or_else(|| self.parent.as_ref()?.get(key))
     }
 
     pub fn contains_key<Q: Eq + ?Sized>(&self, key: &Q) -> bool
     where
         K: Borrow<Q>,
+        H: Lookup<K, V, Q>,
     {
-        self.keys().any(|other| other.borrow() == key)
+        self.get(key).is_some()
+    }
+
+    pub fn iter(&self) -> FlailMapIterator<'_, K, V, H> {
+        self.into_iter()
+    }
+
+    pub fn local_keys(&self) -> impl Iterator<Item = &K> {
+        self.chain.keys()
+    }
+
+    pub fn inherited_keys(&self) -> impl Iterator<Item = &K> {
+        let keys = self.local_keys().collect::<HashSet<_>>();
+
+        self.keys().filter(move |key| !keys.contains(key))
+    }
+
+    pub fn iter_layers(&self) -> impl Iterator<Item = LayerIterator<'_, K, V, H>> {
+        let mut layers = vec![];
+        let mut map = Some(self);
+
+        while let Some(current) = map {
+            layers.push(LayerIterator::Chain(current.chain.iter()));
+            layers.push(LayerIterator::Head(current.head.iter()));
+            map = current.parent.as_deref();
+        }
+
+        layers.into_iter()
     }
 
     pub fn keys(&self) -> impl Iterator<Item = &K> {
@@ -74,7 +165,72 @@ impl<K: Eq + Hash, V> FlailMap<K, V> {
     }
 }
 
-impl<Q: Eq + Hash + ?Sized, K: Eq + Hash, V> Index<&Q> for FlailMap<K, V>
+impl<K: Eq + Hash + Ord, V> FlailMap<K, V, BTreeMap<K, V>> {
+    pub fn iter_sorted(&self) -> FlailMapSortedIterator<'_, K, V> {
+        let mut entries = self.chain.iter().collect::<Vec<_>>();
+
+        entries.sort_by_key(|(key, _)| *key);
+
+        FlailMapSortedIterator {
+            chain_iterator: entries.into_iter().peekable(),
+            head_iterator: self.head.iter().peekable(),
+            parent_iterator: self
+                .parent
+                .as_ref()
+                .map(|parent| Box::new(parent.iter_sorted()).peekable()),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone, H: Lookup<K, V>> FlailMap<K, V, H> {
+    pub fn flatten(&self) -> HashMap<K, V> {
+        self.into_iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
+    pub fn into_flattened(self) -> HashMap<K, V>
+    where
+        H: Clone + IntoIterator<Item = (K, V)>,
+    {
+        let mut map = if let Some(parent) = self.parent {
+            Rc::try_unwrap(parent)
+                .unwrap_or_else(|parent| parent.as_ref().clone())
+                .into_flattened()
+        } else {
+            HashMap::new()
+        };
+
+        map.extend(Rc::try_unwrap(self.head).unwrap_or_else(|head| head.as_ref().clone()));
+        map.extend(HashMap::from(self.chain));
+
+        map
+    }
+
+    pub fn promote(&self) -> Self
+    where
+        H: Clone + Extend<(K, V)>,
+    {
+        let mut head = self.head.as_ref().clone();
+
+        head.extend(self.chain.to_vec());
+
+        Self {
+            chain: Default::default(),
+            head: head.into(),
+            parent: self.parent.clone(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> FlailMap<K, V> {
+    pub fn into_hash_map(self) -> HashMap<K, V> {
+        self.into_flattened()
+    }
+}
+
+impl<Q: Eq + ?Sized, K: Eq + Hash, V, H: Lookup<K, V> + Lookup<K, V, Q>> Index<&Q>
+    for FlailMap<K, V, H>
 where
     K: Borrow<Q>,
 {
@@ -85,29 +241,32 @@ where
     }
 }
 
-impl<K, V> Clone for FlailMap<K, V> {
+impl<K, V, H> Clone for FlailMap<K, V, H> {
     fn clone(&self) -> Self {
         Self {
             chain: self.chain.clone(),
             head: self.head.clone(),
+            parent: self.parent.clone(),
         }
     }
 }
 
-impl<K, V> Default for FlailMap<K, V> {
+impl<K, V, H: Default> Default for FlailMap<K, V, H> {
     fn default() -> Self {
-        Self::new(Default::default())
+        Self::with_head(Default::default())
     }
 }
 
-impl<K: Debug + Eq + Hash, V: Debug> Debug for FlailMap<K, V> {
+impl<K: Debug + Eq + Hash, V: Debug, H: Lookup<K, V>> Debug for FlailMap<K, V, H> {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let len = self.len();
+
         write!(formatter, "{{")?;
 
         for (index, (key, value)) in self.into_iter().enumerate() {
             write!(formatter, "{:?}: {:?}", key, value)?;
 
-            if index < self.len() - 1 {
+            if index < len - 1 {
                 write!(formatter, ", ")?;
             }
         }
@@ -118,7 +277,7 @@ impl<K: Debug + Eq + Hash, V: Debug> Debug for FlailMap<K, V> {
     }
 }
 
-impl<K: Eq + Hash, V: PartialEq> PartialEq for FlailMap<K, V> {
+impl<K: Eq + Hash, V: PartialEq, H: Lookup<K, V>> PartialEq for FlailMap<K, V, H> {
     fn eq(&self, other: &Self) -> bool {
         let set = self.into_iter().collect::<HashMap<_, _>>();
 
@@ -136,7 +295,7 @@ impl<K: Eq + Hash, V: PartialEq> PartialEq for FlailMap<K, V> {
     }
 }
 
-impl<K: Eq + Hash, V: Eq> Eq for FlailMap<K, V> {}
+impl<K: Eq + Hash, V: Eq, H: Lookup<K, V>> Eq for FlailMap<K, V, H> {}
 
 impl<K: Eq + Hash, V> FromIterator<(K, V)> for FlailMap<K, V> {
     fn from_iter<I: IntoIterator<Item = (K, V)>>(iterator: I) -> Self {
@@ -144,26 +303,28 @@ impl<K: Eq + Hash, V> FromIterator<(K, V)> for FlailMap<K, V> {
     }
 }
 
-pub struct FlailMapIterator<'a, K: Eq + Hash, V> {
+pub struct FlailMapIterator<'a, K: Eq + Hash, V, H: Lookup<K, V> + 'a = HashMap<K, V>> {
     chain_iterator: map::MapIterator<'a, K, V>,
-    head_iterator: hash_map::Iter<'a, K, V>,
+    head_iterator: H::Iterator<'a>,
+    parent_iterator: Option<Box<FlailMapIterator<'a, K, V, H>>>,
     set: HashSet<&'a K>,
 }
 
-impl<'a, K: Eq + Hash, V> IntoIterator for &'a FlailMap<K, V> {
+impl<'a, K: Eq + Hash, V, H: Lookup<K, V>> IntoIterator for &'a FlailMap<K, V, H> {
     type Item = (&'a K, &'a V);
-    type IntoIter = FlailMapIterator<'a, K, V>;
+    type IntoIter = FlailMapIterator<'a, K, V, H>;
 
     fn into_iter(self) -> Self::IntoIter {
         FlailMapIterator {
             chain_iterator: self.chain.into_iter(),
             head_iterator: self.head.iter(),
+            parent_iterator: self.parent.as_ref().map(|parent| parent.into_iter().into()),
             set: Default::default(),
         }
     }
 }
 
-impl<'a, K: Eq + Hash, V> Iterator for FlailMapIterator<'a, K, V> {
+impl<'a, K: Eq + Hash, V, H: Lookup<K, V>> Iterator for FlailMapIterator<'a, K, V, H> {
     type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -182,6 +343,14 @@ impl<'a, K: Eq + Hash, V> Iterator for FlailMapIterator<'a, K, V> {
 
             self.set.insert(key);
 
+            Some((key, value))
+        } else if let Some((key, value)) = self.parent_iterator.as_mut()?.next() {
+            if self.set.contains(key) {
+                return self.next();
+            }
+
+            self.set.insert(key);
+
             Some((key, value))
         } else {
             None
@@ -189,10 +358,58 @@ impl<'a, K: Eq + Hash, V> Iterator for FlailMapIterator<'a, K, V> {
     }
 }
 
+pub struct FlailMapSortedIterator<'a, K: Ord, V> {
+    chain_iterator: Peekable<vec::IntoIter<(&'a K, &'a V)>>,
+    head_iterator: Peekable<btree_map::Iter<'a, K, V>>,
+    parent_iterator: Option<Peekable<Box<FlailMapSortedIterator<'a, K, V>>>>,
+}
+
+impl<'a, K: Ord, V> Iterator for FlailMapSortedIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = [
+            self.chain_iterator.peek().map(|(key, _)| *key),
+            self.head_iterator.peek().map(|(key, _)| *key),
+            self.parent_iterator
+                .as_mut()
+                .and_then(|iterator| iterator.peek())
+                .map(|(key, _)| *key),
+        ]
+        .into_iter()
+        .flatten()
+        .min()?;
+
+        let chain = self.chain_iterator.next_if(|(other, _)| *other == key);
+        let head = self.head_iterator.next_if(|(other, _)| *other == key);
+        let parent = self
+            .parent_iterator
+            .as_mut()
+            .and_then(|iterator| iterator.next_if(|(other, _)| *other == key));
+
+        chain.or(head).or(parent)
+    }
+}
+
+pub enum LayerIterator<'a, K: Eq + Hash, V, H: Lookup<K, V> + 'a = HashMap<K, V>> {
+    Chain(map::MapIterator<'a, K, V>),
+    Head(H::Iterator<'a>),
+}
+
+impl<'a, K: Eq + Hash, V, H: Lookup<K, V>> Iterator for LayerIterator<'a, K, V, H> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Chain(iterator) => iterator.next(),
+            Self::Head(iterator) => iterator.next(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::BTreeMap;
 
     #[test]
     fn new() {
@@ -399,4 +616,297 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn iter() {
+        let map = FlailMap::new([(1, 1)].into_iter().collect()).insert(2, 2);
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            (&map).into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn into_hash_map() {
+        let map =
+            FlailMap::new([(1, 1), (2, 2)].into_iter().collect()).insert_iter([(1, 3), (3, 3)]);
+
+        assert_eq!(
+            map.clone().into_hash_map(),
+            HashMap::from([(1, 3), (2, 2), (3, 3)])
+        );
+        assert_eq!(map.len(), 3);
+        assert_eq!(
+            FlailMap::new(HashMap::from([(1, 1)])).into_hash_map(),
+            HashMap::from([(1, 1)])
+        );
+    }
+
+    #[test]
+    fn push_layer() {
+        let map = FlailMap::new([(1, 1), (2, 2)].into_iter().collect())
+            .insert(3, 3)
+            .push_layer([(2, 4)].into_iter().collect())
+            .insert(5, 5);
+
+        assert_eq!(map.depth(), 1);
+        assert_eq!(map.get(&1), Some(&1));
+        assert_eq!(map.get(&2), Some(&4));
+        assert_eq!(map.get(&3), Some(&3));
+        assert_eq!(map.get(&5), Some(&5));
+        assert_eq!(map.len(), 4);
+        assert_eq!(map, FlailMap::from_iter([(1, 1), (2, 4), (3, 3), (5, 5)]));
+    }
+
+    #[test]
+    fn pop_layer() {
+        let map = FlailMap::new([(1, 1)].into_iter().collect()).insert(2, 2);
+        let layered = map.push_layer([(1, 3)].into_iter().collect()).insert(4, 4);
+
+        assert_eq!(layered.pop_layer(), Some(map.clone()));
+        assert_eq!(layered.pop_layer().unwrap().depth(), 0);
+        assert_eq!(map.pop_layer(), None);
+    }
+
+    #[test]
+    fn is_empty_with_layers() {
+        assert!(FlailMap::<(), ()>::default()
+            .push_layer(Default::default())
+            .is_empty());
+        assert!(!FlailMap::from_iter([(1, 1)])
+            .push_layer(Default::default())
+            .is_empty());
+    }
+
+    #[test]
+    fn into_hash_map_with_layers() {
+        assert_eq!(
+            FlailMap::from_iter([(1, 1), (2, 2)])
+                .insert(3, 3)
+                .push_layer([(2, 4)].into_iter().collect())
+                .insert(3, 5)
+                .into_hash_map(),
+            HashMap::from([(1, 1), (2, 4), (3, 5)])
+        );
+    }
+
+    #[test]
+    fn with_head() {
+        let map = FlailMap::with_head(BTreeMap::from([(1, 1), (2, 2)])).insert(2, 3);
+
+        assert_eq!(map.get(&1), Some(&1));
+        assert_eq!(map.get(&2), Some(&3));
+        assert_eq!(map.get(&3), None);
+        assert_eq!(map[&2], 3);
+        assert_eq!(map.len(), 2);
+        assert_eq!(
+            map,
+            FlailMap::with_head(BTreeMap::from([(1, 1)])).insert(2, 3)
+        );
+    }
+
+    #[test]
+    fn with_head_borrowed_key() {
+        let map =
+            FlailMap::with_head(BTreeMap::from([("foo".to_string(), 1)])).insert("bar".into(), 2);
+
+        assert_eq!(map.get("foo"), Some(&1));
+        assert_eq!(map.get("bar"), Some(&2));
+    }
+
+    #[test]
+    fn new_over() {
+        let parent = FlailMap::new([(1, 1)].into_iter().collect()).insert(2, 2);
+        let map = FlailMap::new_over(parent.clone()).insert(1, 3);
+
+        assert_eq!(map.depth(), 1);
+        assert_eq!(map.get(&1), Some(&3));
+        assert_eq!(map.get(&2), Some(&2));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.pop_layer(), Some(parent.clone()));
+        assert!(Rc::ptr_eq(&map.pop_layer().unwrap().head, &parent.head));
+    }
+
+    #[test]
+    fn new_ordered() {
+        let map = FlailMap::new_ordered(BTreeMap::from([(3, 3), (1, 1)])).insert_iter([
+            (4, 4),
+            (2, 2),
+            (3, 5),
+        ]);
+
+        assert_eq!(map.len(), 4);
+        assert!(map.contains_key(&4));
+        assert_eq!(
+            map.iter_sorted().collect::<Vec<_>>(),
+            vec![(&1, &1), (&2, &2), (&3, &5), (&4, &4)]
+        );
+    }
+
+    #[test]
+    fn iter_sorted() {
+        assert_eq!(
+            FlailMap::new_ordered(BTreeMap::from([(2, 2), (5, 5)]))
+                .insert_iter([(3, 3), (1, 1), (2, 4), (3, 6)])
+                .push_layer(BTreeMap::from([(0, 0), (4, 4), (5, 7)]))
+                .insert_iter([(6, 6), (1, 8)])
+                .iter_sorted()
+                .collect::<Vec<_>>(),
+            vec![
+                (&0, &0),
+                (&1, &8),
+                (&2, &4),
+                (&3, &6),
+                (&4, &4),
+                (&5, &7),
+                (&6, &6)
+            ]
+        );
+        assert_eq!(
+            FlailMap::<usize, usize, _>::new_ordered(Default::default())
+                .iter_sorted()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn head() {
+        let map = FlailMap::new([(1, 1)].into_iter().collect()).insert(2, 2);
+
+        assert_eq!(map.head(), &HashMap::from([(1, 1)]));
+        assert_eq!(map.chain(), &Map::new().insert(2, 2));
+    }
+
+    #[test]
+    fn flatten() {
+        let map = FlailMap::new([(1, 1), (2, 2)].into_iter().collect())
+            .insert(2, 3)
+            .push_layer([(3, 3)].into_iter().collect())
+            .insert(1, 4);
+
+        assert_eq!(map.flatten(), HashMap::from([(1, 4), (2, 3), (3, 3)]));
+        assert_eq!(
+            FlailMap::new_ordered(BTreeMap::from([(1, 1)]))
+                .insert(2, 2)
+                .flatten(),
+            HashMap::from([(1, 1), (2, 2)])
+        );
+        assert_eq!(FlailMap::new(map.flatten()), map);
+    }
+
+    #[test]
+    fn into_flattened() {
+        let map = FlailMap::new([(1, 1), (2, 2)].into_iter().collect()).insert(2, 3);
+
+        assert_eq!(map.clone().into_flattened(), map.flatten());
+    }
+
+    #[test]
+    fn into_flattened_ordered() {
+        let map = FlailMap::new_ordered(BTreeMap::from([(1, 1), (2, 2)]))
+            .insert(2, 3)
+            .push_layer(BTreeMap::from([(3, 3)]))
+            .insert(1, 4);
+
+        assert_eq!(
+            map.clone().into_flattened(),
+            HashMap::from([(1, 4), (2, 3), (3, 3)])
+        );
+        assert_eq!(map.clone().into_flattened(), map.flatten());
+    }
+
+    #[test]
+    fn rebase() {
+        let map = FlailMap::new([(1, 1), (2, 2)].into_iter().collect()).insert(2, 3);
+        let rebased = map.rebase([(1, 4), (5, 5)].into_iter().collect());
+
+        assert_eq!(rebased.get(&1), Some(&4));
+        assert_eq!(rebased.get(&2), Some(&3));
+        assert_eq!(rebased.get(&5), Some(&5));
+        assert_eq!(map.get(&1), Some(&1));
+        assert_eq!(
+            map.push_layer([(4, 4)].into_iter().collect())
+                .insert(3, 3)
+                .rebase(Default::default()),
+            FlailMap::from_iter([(1, 1), (2, 3), (3, 3)])
+        );
+    }
+
+    #[test]
+    fn promote() {
+        let map = FlailMap::new([(1, 1), (2, 2)].into_iter().collect()).insert_iter([
+            (2, 3),
+            (3, 3),
+            (3, 4),
+        ]);
+        let promoted = map.promote();
+
+        assert_eq!(promoted, map);
+        assert!(promoted.chain().is_empty());
+        assert_eq!(promoted.head(), &HashMap::from([(1, 1), (2, 3), (3, 4)]));
+        assert_eq!(map.head(), &HashMap::from([(1, 1), (2, 2)]));
+    }
+
+    #[test]
+    fn promote_with_layers() {
+        let map = FlailMap::new_ordered(BTreeMap::from([(1, 1)]))
+            .push_layer(BTreeMap::from([(2, 2)]))
+            .insert(1, 3);
+        let promoted = map.promote();
+
+        assert_eq!(promoted, map);
+        assert_eq!(promoted.head(), &BTreeMap::from([(1, 3), (2, 2)]));
+        assert_eq!(promoted.depth(), 1);
+    }
+
+    #[test]
+    fn iter_layers() {
+        let map = FlailMap::new_ordered(BTreeMap::from([(1, 1), (2, 2)]))
+            .insert(1, 3)
+            .push_layer(BTreeMap::from([(3, 3)]))
+            .insert_iter([(2, 4), (2, 5)]);
+
+        assert_eq!(
+            map.iter_layers()
+                .map(|layer| layer.collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![(&2, &5)],
+                vec![(&3, &3)],
+                vec![(&1, &3)],
+                vec![(&1, &1), (&2, &2)],
+            ]
+        );
+    }
+
+    #[test]
+    fn local_keys() {
+        let map =
+            FlailMap::new([(1, 1), (2, 2)].into_iter().collect()).insert_iter([(2, 3), (3, 3)]);
+
+        assert_eq!(
+            map.local_keys().collect::<HashSet<_>>(),
+            HashSet::from([&2, &3])
+        );
+        assert_eq!(
+            FlailMap::<usize, usize>::new(Default::default())
+                .local_keys()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn inherited_keys() {
+        let map = FlailMap::new([(1, 1), (2, 2)].into_iter().collect())
+            .push_layer([(4, 4)].into_iter().collect())
+            .insert_iter([(2, 3), (3, 3)]);
+
+        assert_eq!(
+            map.inherited_keys().collect::<HashSet<_>>(),
+            HashSet::from([&1, &4])
+        );
+    }
 }
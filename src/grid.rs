@@ -0,0 +1,234 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    ops::Index,
+    rc::Rc,
+    slice,
+};
+
+pub struct Grid<T> {
+    rows: Rc<Vec<Rc<Vec<T>>>>,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.rows.get(y)?.get(x)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        self.rows.get(y).map(|row| row.as_slice())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.rows.iter().map(|row| row.as_slice())
+    }
+
+    pub fn iter(&self) -> GridIterator<'_, T> {
+        self.into_iter()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        let row = Rc::new(vec![value; width]);
+
+        Self {
+            rows: Rc::new((0..height).map(|_| row.clone()).collect()),
+            width,
+        }
+    }
+
+    pub fn set(&self, x: usize, y: usize, value: T) -> Option<Self> {
+        if x >= self.width || y >= self.height() {
+            return None;
+        }
+
+        let mut row = self.rows[y].as_ref().clone();
+        row[x] = value;
+
+        let mut rows = self.rows.as_ref().clone();
+        rows[y] = row.into();
+
+        Some(Self {
+            rows: rows.into(),
+            width: self.width,
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).expect("index in bounds")
+    }
+}
+
+impl<T> Clone for Grid<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows.clone(),
+            width: self.width,
+        }
+    }
+}
+
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "[")?;
+
+        for (index, row) in self.rows().enumerate() {
+            write!(formatter, "{:?}", row)?;
+
+            if index < self.height() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "]")?;
+
+        Ok(())
+    }
+}
+
+impl<T: PartialEq> PartialEq for Grid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.rows == other.rows
+    }
+}
+
+impl<T: Eq> Eq for Grid<T> {}
+
+pub struct GridIterator<'a, T> {
+    rows_iterator: slice::Iter<'a, Rc<Vec<T>>>,
+    row_iterator: slice::Iter<'a, T>,
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = &'a T;
+    type IntoIter = GridIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        GridIterator {
+            rows_iterator: self.rows.iter(),
+            row_iterator: [].iter(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for GridIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.row_iterator.next() {
+                return Some(value);
+            }
+
+            self.row_iterator = self.rows_iterator.next()?.iter();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let grid = Grid::new(3, 2, 0);
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(Grid::new(2, 2, 0), Grid::new(2, 2, 0));
+        assert_ne!(Grid::new(2, 2, 0), Grid::new(2, 2, 1));
+        assert_ne!(Grid::new(0, 2, 0), Grid::new(1, 2, 0));
+        assert_ne!(Grid::new(2, 2, 0), Grid::new(2, 2, 0).set(1, 1, 1).unwrap());
+    }
+
+    #[test]
+    fn get() {
+        let grid = Grid::new(3, 2, 0).set(2, 1, 42).unwrap();
+
+        assert_eq!(grid.get(2, 1), Some(&42));
+        assert_eq!(grid.get(1, 2), None);
+        assert_eq!(grid.get(0, 0), Some(&0));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid[(2, 1)], 42);
+    }
+
+    #[test]
+    fn set() {
+        let grid = Grid::new(2, 2, 0);
+        let other = grid.set(0, 1, 1).unwrap();
+
+        assert_eq!(grid.get(0, 1), Some(&0));
+        assert_eq!(other.get(0, 1), Some(&1));
+        assert!(grid.set(2, 0, 1).is_none());
+        assert!(grid.set(0, 2, 1).is_none());
+    }
+
+    #[test]
+    fn set_shares_rows() {
+        let grid = Grid::new(2, 2, 0);
+        let other = grid.set(0, 1, 1).unwrap();
+
+        assert!(Rc::ptr_eq(&grid.rows[0], &other.rows[0]));
+        assert!(!Rc::ptr_eq(&grid.rows[1], &other.rows[1]));
+    }
+
+    #[test]
+    fn rows() {
+        let grid = Grid::new(2, 2, 0).set(1, 0, 1).unwrap();
+
+        assert_eq!(grid.row(0), Some([0, 1].as_slice()));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![[0, 1].as_slice(), [0, 0].as_slice()]
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            Grid::new(2, 2, 0)
+                .set(1, 0, 1)
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![&0, &1, &0, &0]
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", Grid::new(0, 0, ())), "[]");
+        assert_eq!(
+            format!("{:?}", Grid::new(2, 2, 0).set(1, 0, 1).unwrap()),
+            "[[0, 1], [0, 0]]"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let grid = Grid::new(2, 2, 0).set(1, 0, 1).unwrap();
+
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            (&grid).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{List, Map};
+
+#[derive(Clone, Debug)]
+pub struct History<T> {
+    current: T,
+    undo: List<T>,
+    redo: List<T>,
+    checkpoints: Map<String, T>,
+}
+
+impl<T> History<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            current: value,
+            undo: Default::default(),
+            redo: Default::default(),
+            checkpoints: Default::default(),
+        }
+    }
+
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+impl<T: Clone> History<T> {
+    pub fn commit(&self, value: T) -> Self {
+        Self {
+            current: value,
+            undo: self.undo.push_front(self.current.clone()),
+            redo: Default::default(),
+            checkpoints: self.checkpoints.clone(),
+        }
+    }
+
+    pub fn undo(&self) -> Option<Self> {
+        Some(Self {
+            current: self.undo.first()?.clone(),
+            undo: self.undo.pop_front(),
+            redo: self.redo.push_front(self.current.clone()),
+            checkpoints: self.checkpoints.clone(),
+        })
+    }
+
+    pub fn redo(&self) -> Option<Self> {
+        Some(Self {
+            current: self.redo.first()?.clone(),
+            undo: self.undo.push_front(self.current.clone()),
+            redo: self.redo.pop_front(),
+            checkpoints: self.checkpoints.clone(),
+        })
+    }
+
+    pub fn checkpoint(&self, label: impl Into<String>) -> Self {
+        Self {
+            current: self.current.clone(),
+            undo: self.undo.clone(),
+            redo: self.redo.clone(),
+            checkpoints: self.checkpoints.insert(label.into(), self.current.clone()),
+        }
+    }
+
+    pub fn restore(&self, label: &str) -> Option<Self> {
+        Some(self.commit(self.checkpoints.get(label)?.clone()))
+    }
+}
+
+impl<T: Default> Default for History<T> {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        History::new(());
+    }
+
+    #[test]
+    fn current() {
+        assert_eq!(History::new(1).current(), &1);
+        assert_eq!(History::new(1).commit(2).current(), &2);
+    }
+
+    #[test]
+    fn undo() {
+        let history = History::new(1).commit(2).commit(3);
+
+        assert_eq!(history.undo().unwrap().current(), &2);
+        assert_eq!(history.undo().unwrap().undo().unwrap().current(), &1);
+        assert!(history.undo().unwrap().undo().unwrap().undo().is_none());
+    }
+
+    #[test]
+    fn redo() {
+        let history = History::new(1).commit(2).commit(3);
+
+        assert!(history.redo().is_none());
+        assert_eq!(history.undo().unwrap().redo().unwrap().current(), &3);
+        assert_eq!(
+            history
+                .undo()
+                .unwrap()
+                .undo()
+                .unwrap()
+                .redo()
+                .unwrap()
+                .current(),
+            &2
+        );
+    }
+
+    #[test]
+    fn commit_clears_redo() {
+        let history = History::new(1).commit(2).undo().unwrap().commit(3);
+
+        assert!(history.redo().is_none());
+        assert_eq!(history.undo().unwrap().current(), &1);
+    }
+
+    #[test]
+    fn can_undo() {
+        assert!(!History::new(1).can_undo());
+        assert!(History::new(1).commit(2).can_undo());
+    }
+
+    #[test]
+    fn can_redo() {
+        assert!(!History::new(1).commit(2).can_redo());
+        assert!(History::new(1).commit(2).undo().unwrap().can_redo());
+    }
+
+    #[test]
+    fn checkpoint() {
+        let history = History::new(1).checkpoint("foo").commit(2).commit(3);
+
+        assert_eq!(history.restore("foo").unwrap().current(), &1);
+        assert_eq!(
+            history.restore("foo").unwrap().undo().unwrap().current(),
+            &3
+        );
+        assert!(history.restore("bar").is_none());
+    }
+
+    #[test]
+    fn share_versions() {
+        let map = Map::new().insert("foo", 1);
+        let history = History::new(map.clone()).commit(map.insert("bar", 2));
+
+        assert_eq!(history.current().get("bar"), Some(&2));
+        assert_eq!(history.undo().unwrap().current(), &map);
+    }
+}
@@ -0,0 +1,280 @@
+use crate::List;
+use std::{
+    borrow::Borrow,
+    fmt::{self, Debug, Formatter},
+    iter::Rev,
+    ops::Index,
+    vec,
+};
+
+pub struct IndexedMap<K, V>(List<(K, V)>);
+
+impl<K, V> IndexedMap<K, V> {
+    pub fn new() -> Self {
+        Self(Default::default())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get<Q: Eq + ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.0.into_iter().find_map(|(other_key, value)| {
+            if other_key.borrow() == key {
+                Some(value)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        if index < self.len() {
+            self.0
+                .into_iter()
+                .nth(self.len() - 1 - index)
+                .map(|(key, value)| (key, value))
+        } else {
+            None
+        }
+    }
+
+    pub fn index_of<Q: Eq + ?Sized>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        self.0
+            .into_iter()
+            .position(|(other, _)| other.borrow() == key)
+            .map(|position| self.len() - 1 - position)
+    }
+
+    pub fn contains_key<Q: Eq + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.get(key).is_some()
+    }
+
+    pub fn iter(&self) -> IndexedMapIterator<'_, K, V> {
+        self.into_iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.into_iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.into_iter().map(|(_, value)| value)
+    }
+}
+
+impl<K: Clone + Eq, V: Clone> IndexedMap<K, V> {
+    pub fn insert(&self, key: K, value: V) -> Self {
+        if let Some(position) = self.0.position(|(other, _)| other == &key) {
+            Self(
+                self.0
+                    .update_at(position, (key, value))
+                    .expect("index in bounds"),
+            )
+        } else {
+            Self(self.0.push_front((key, value)))
+        }
+    }
+
+    pub fn insert_iter(&self, iterator: impl IntoIterator<Item = (K, V)>) -> Self {
+        let mut map = self.clone();
+
+        for (key, value) in iterator {
+            map = map.insert(key, value);
+        }
+
+        map
+    }
+}
+
+impl<Q: Eq + ?Sized, K: Eq, V> Index<&Q> for IndexedMap<K, V>
+where
+    K: Borrow<Q>,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("existent key")
+    }
+}
+
+impl<K, V> Clone for IndexedMap<K, V> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K, V> Default for IndexedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for IndexedMap<K, V> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{{")?;
+
+        for (index, (key, value)) in self.into_iter().enumerate() {
+            write!(formatter, "{:?}: {:?}", key, value)?;
+
+            if index < self.len() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "}}")?;
+
+        Ok(())
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for IndexedMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.into_iter().eq(other)
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for IndexedMap<K, V> {}
+
+impl<K: Clone + Eq, V: Clone> FromIterator<(K, V)> for IndexedMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iterator: I) -> Self {
+        Self::new().insert_iter(iterator)
+    }
+}
+
+pub struct IndexedMapIterator<'a, K, V>(Rev<vec::IntoIter<&'a (K, V)>>);
+
+impl<'a, K, V> IntoIterator for &'a IndexedMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = IndexedMapIterator<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IndexedMapIterator(self.0.into_iter().collect::<Vec<_>>().into_iter().rev())
+    }
+}
+
+impl<'a, K, V> Iterator for IndexedMapIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, value)| (key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        IndexedMap::<(), ()>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(IndexedMap::<(), ()>::new(), IndexedMap::new());
+        assert_ne!(IndexedMap::new(), IndexedMap::new().insert(1, 1));
+        assert_eq!(
+            IndexedMap::new().insert(1, 1),
+            IndexedMap::new().insert(1, 2).insert(1, 1)
+        );
+        assert_ne!(
+            IndexedMap::new().insert(1, 1).insert(2, 2),
+            IndexedMap::new().insert(2, 2).insert(1, 1)
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(IndexedMap::<(), ()>::new().len(), 0);
+        assert_eq!(IndexedMap::new().insert(1, 1).len(), 1);
+        assert_eq!(IndexedMap::new().insert(1, 1).insert(1, 2).len(), 1);
+        assert_eq!(IndexedMap::new().insert(1, 1).insert(2, 2).len(), 2);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(IndexedMap::<(), ()>::new().is_empty());
+        assert!(!IndexedMap::new().insert(1, 1).is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let map = IndexedMap::new().insert(1, 2).insert(3, 4);
+
+        assert_eq!(map.get(&1), Some(&2));
+        assert_eq!(map.get(&3), Some(&4));
+        assert_eq!(map.get(&4), None);
+    }
+
+    #[test]
+    fn get_index() {
+        let map = IndexedMap::new().insert(1, 2).insert(3, 4);
+
+        assert_eq!(map.get_index(0), Some((&1, &2)));
+        assert_eq!(map.get_index(1), Some((&3, &4)));
+        assert_eq!(map.get_index(2), None);
+    }
+
+    #[test]
+    fn index_of() {
+        let map = IndexedMap::new().insert(1, 2).insert(3, 4);
+
+        assert_eq!(map.index_of(&1), Some(0));
+        assert_eq!(map.index_of(&3), Some(1));
+        assert_eq!(map.index_of(&5), None);
+    }
+
+    #[test]
+    fn insert_existing_key() {
+        let map = IndexedMap::new().insert(1, 1).insert(2, 2).insert(3, 3);
+        let other = map.insert(2, 4);
+
+        assert_eq!(other.get_index(1), Some((&2, &4)));
+        assert_eq!(other.index_of(&2), Some(1));
+        assert_eq!(other.len(), 3);
+        assert_eq!(map.get(&2), Some(&2));
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            IndexedMap::from_iter([(1, 1), (2, 2), (3, 3), (2, 4)])
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![(&1, &1), (&2, &4), (&3, &3)]
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", IndexedMap::<(), ()>::new()), "{}");
+        assert_eq!(
+            format!("{:?}", IndexedMap::from_iter([(1, 2), (3, 4)])),
+            "{1: 2, 3: 4}"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let map = IndexedMap::from_iter([(1, 1), (2, 2)]);
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            (&map).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use crate::FlailMap;
+use std::{collections::HashMap, rc::Rc};
+
+const MAX_CHAIN_LENGTH: usize = 32;
+
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    symbols: FlailMap<Rc<str>, Rc<str>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Snapshot(FlailMap<Rc<str>, Rc<str>>);
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<Rc<str>> {
+        self.symbols.get(name).cloned()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.symbols.get(name).is_some()
+    }
+
+    pub fn intern(&mut self, name: &str) -> Rc<str> {
+        if let Some(symbol) = self.get(name) {
+            symbol
+        } else {
+            let symbol = Rc::<str>::from(name);
+
+            self.symbols = self.symbols.insert(symbol.clone(), symbol.clone());
+
+            if self.symbols.chain().raw_len() > MAX_CHAIN_LENGTH {
+                self.compact();
+            }
+
+            symbol
+        }
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot(self.symbols.clone())
+    }
+
+    pub fn rollback(&mut self, snapshot: Snapshot) {
+        self.symbols = snapshot.0;
+    }
+
+    fn compact(&mut self) {
+        let mut head = HashMap::from(self.symbols.chain().clone());
+        let mut parent = self.symbols.pop_layer();
+
+        while let Some(layer) = parent.take_if(|layer| layer.head().len() <= head.len()) {
+            head.extend(
+                layer
+                    .head()
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone())),
+            );
+            parent = layer.pop_layer();
+        }
+
+        self.symbols = match parent {
+            Some(parent) => parent.push_layer(head),
+            None => FlailMap::new(head),
+        }
+        .push_layer(Default::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        Interner::new();
+    }
+
+    #[test]
+    fn intern() {
+        let mut interner = Interner::new();
+        let foo = interner.intern("foo");
+
+        assert_eq!(&*foo, "foo");
+        assert!(Rc::ptr_eq(&foo, &interner.intern("foo")));
+        assert!(!Rc::ptr_eq(&foo, &interner.intern("bar")));
+    }
+
+    #[test]
+    fn intern_many() {
+        let mut interner = Interner::new();
+
+        for index in 0..1000 {
+            interner.intern(&index.to_string());
+        }
+
+        assert_eq!(interner.len(), 1000);
+        assert!(interner.symbols.chain().raw_len() <= MAX_CHAIN_LENGTH);
+        assert!(interner.symbols.depth() <= 10);
+
+        for index in 0..1000 {
+            assert!(interner.contains(&index.to_string()));
+        }
+    }
+
+    #[test]
+    fn len() {
+        let mut interner = Interner::new();
+
+        assert_eq!(interner.len(), 0);
+        assert!(interner.is_empty());
+
+        interner.intern("foo");
+        interner.intern("foo");
+        interner.intern("bar");
+
+        assert_eq!(interner.len(), 2);
+        assert!(!interner.is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let mut interner = Interner::new();
+        let foo = interner.intern("foo");
+
+        assert!(Rc::ptr_eq(&interner.get("foo").unwrap(), &foo));
+        assert_eq!(interner.get("bar"), None);
+        assert!(interner.contains("foo"));
+        assert!(!interner.contains("bar"));
+    }
+
+    #[test]
+    fn rollback() {
+        let mut interner = Interner::new();
+        let foo = interner.intern("foo");
+        let snapshot = interner.snapshot();
+
+        interner.intern("bar");
+        interner.rollback(snapshot);
+
+        assert!(interner.contains("foo"));
+        assert!(!interner.contains("bar"));
+        assert!(Rc::ptr_eq(&interner.intern("foo"), &foo));
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn rollback_many() {
+        let mut interner = Interner::new();
+        let foo = interner.intern("foo");
+        let snapshot = interner.snapshot();
+
+        for index in 0..1000 {
+            interner.intern(&index.to_string());
+        }
+
+        assert!(interner.symbols.chain().raw_len() <= MAX_CHAIN_LENGTH);
+
+        interner.rollback(snapshot);
+
+        assert_eq!(interner.len(), 1);
+        assert!(!interner.contains("0"));
+        assert!(Rc::ptr_eq(&interner.intern("foo"), &foo));
+    }
+
+    #[test]
+    fn snapshot_shares_symbols() {
+        fn root(mut symbols: FlailMap<Rc<str>, Rc<str>>) -> FlailMap<Rc<str>, Rc<str>> {
+            while let Some(parent) = symbols.pop_layer() {
+                symbols = parent;
+            }
+
+            symbols
+        }
+
+        let mut interner = Interner::new();
+
+        for index in 0..1000 {
+            interner.intern(&index.to_string());
+        }
+
+        let snapshot = interner.snapshot();
+
+        for index in 1000..1040 {
+            interner.intern(&index.to_string());
+        }
+
+        assert!(std::ptr::eq(
+            root(interner.symbols.clone()).head(),
+            root(snapshot.0.clone()).head()
+        ));
+    }
+
+    #[test]
+    fn rollback_nested() {
+        let mut interner = Interner::new();
+        let outer = interner.snapshot();
+
+        interner.intern("foo");
+
+        let inner = interner.snapshot();
+
+        interner.intern("bar");
+        interner.rollback(inner);
+
+        assert_eq!(interner.len(), 1);
+
+        interner.rollback(outer);
+
+        assert!(interner.is_empty());
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{list, List};
+use std::{
+    fmt::{self, Debug, Formatter},
+    ops::Range,
+};
+
+pub struct IntervalSet<K>(List<Range<K>>);
+
+impl<K> IntervalSet<K> {
+    pub fn new() -> Self {
+        Self(Default::default())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> IntervalSetIterator<'_, K> {
+        self.into_iter()
+    }
+}
+
+impl<K: Ord> IntervalSet<K> {
+    pub fn contains_point(&self, point: &K) -> bool {
+        self.0
+            .into_iter()
+            .take_while(|range| &range.start <= point)
+            .any(|range| range.contains(point))
+    }
+}
+
+impl<K: Clone + Ord> IntervalSet<K> {
+    pub fn insert_range(&self, range: Range<K>) -> Self {
+        if range.is_empty() {
+            return self.clone();
+        }
+
+        let Range { mut start, mut end } = range;
+        let mut prefix = vec![];
+        let mut rest = self.0.clone();
+
+        while let Some(other) = rest.first().cloned() {
+            if other.end < start {
+                prefix.push(other);
+            } else if other.start <= end {
+                start = start.min(other.start);
+                end = end.max(other.end);
+            } else {
+                break;
+            }
+
+            rest = rest.pop_front();
+        }
+
+        Self(
+            rest.push_front(start..end)
+                .push_front_iter(prefix.into_iter().rev()),
+        )
+    }
+
+    pub fn insert_range_iter(&self, iterator: impl IntoIterator<Item = Range<K>>) -> Self {
+        let mut set = self.clone();
+
+        for range in iterator {
+            set = set.insert_range(range);
+        }
+
+        set
+    }
+
+    pub fn remove_range(&self, range: Range<K>) -> Self {
+        if range.is_empty() {
+            return self.clone();
+        }
+
+        let mut prefix = vec![];
+        let mut rest = self.0.clone();
+
+        while let Some(other) = rest.first().cloned() {
+            if other.end <= range.start {
+                prefix.push(other);
+            } else if other.start < range.end {
+                if other.start < range.start {
+                    prefix.push(other.start..range.start.clone());
+                }
+
+                if range.end < other.end {
+                    prefix.push(range.end.clone()..other.end);
+                }
+            } else {
+                break;
+            }
+
+            rest = rest.pop_front();
+        }
+
+        Self(rest.push_front_iter(prefix.into_iter().rev()))
+    }
+}
+
+impl<K> Clone for IntervalSet<K> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K> Default for IntervalSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug> Debug for IntervalSet<K> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self.0)
+    }
+}
+
+impl<K: PartialEq> PartialEq for IntervalSet<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: Eq> Eq for IntervalSet<K> {}
+
+impl<K: Clone + Ord> FromIterator<Range<K>> for IntervalSet<K> {
+    fn from_iter<I: IntoIterator<Item = Range<K>>>(iterator: I) -> Self {
+        Self::new().insert_range_iter(iterator)
+    }
+}
+
+pub struct IntervalSetIterator<'a, K>(list::ListIterator<'a, Range<K>>);
+
+impl<'a, K> IntoIterator for &'a IntervalSet<K> {
+    type Item = &'a Range<K>;
+    type IntoIter = IntervalSetIterator<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntervalSetIterator(self.0.into_iter())
+    }
+}
+
+impl<'a, K> Iterator for IntervalSetIterator<'a, K> {
+    type Item = &'a Range<K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        IntervalSet::<()>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(IntervalSet::<()>::new(), IntervalSet::new());
+        assert_ne!(IntervalSet::new(), IntervalSet::new().insert_range(0..1));
+        assert_eq!(
+            IntervalSet::from_iter([0..1, 1..2]),
+            IntervalSet::new().insert_range(0..2)
+        );
+        assert_eq!(
+            IntervalSet::from_iter([4..5, 0..1]),
+            IntervalSet::from_iter([0..1, 4..5])
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(IntervalSet::<()>::new().len(), 0);
+        assert_eq!(IntervalSet::new().insert_range(0..1).len(), 1);
+        assert_eq!(IntervalSet::from_iter([0..1, 2..3]).len(), 2);
+        assert_eq!(IntervalSet::from_iter([0..2, 1..3]).len(), 1);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(IntervalSet::<()>::new().is_empty());
+        assert!(IntervalSet::new().insert_range(1..1).is_empty());
+        assert!(!IntervalSet::new().insert_range(0..1).is_empty());
+    }
+
+    #[test]
+    fn contains_point() {
+        let set = IntervalSet::from_iter([0..2, 4..6]);
+
+        assert!(set.contains_point(&0));
+        assert!(set.contains_point(&1));
+        assert!(!set.contains_point(&2));
+        assert!(!set.contains_point(&3));
+        assert!(set.contains_point(&5));
+        assert!(!set.contains_point(&6));
+        assert!(!set.contains_point(&-1));
+    }
+
+    #[test]
+    fn insert_range() {
+        let set = IntervalSet::from_iter([0..2, 4..6, 8..10]);
+
+        assert_eq!(
+            set.insert_range(3..4).into_iter().collect::<Vec<_>>(),
+            vec![&(0..2), &(3..6), &(8..10)]
+        );
+        assert_eq!(
+            set.insert_range(1..9).into_iter().collect::<Vec<_>>(),
+            vec![&(0..10)]
+        );
+        assert_eq!(
+            set.insert_range(11..12).into_iter().collect::<Vec<_>>(),
+            vec![&(0..2), &(4..6), &(8..10), &(11..12)]
+        );
+        assert_eq!(
+            set.insert_range(-2..-1).into_iter().collect::<Vec<_>>(),
+            vec![&(-2..-1), &(0..2), &(4..6), &(8..10)]
+        );
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn remove_range() {
+        let set = IntervalSet::from_iter([0..4, 6..10]);
+
+        assert_eq!(
+            set.remove_range(1..2),
+            IntervalSet::from_iter([0..1, 2..4, 6..10])
+        );
+        assert_eq!(
+            set.remove_range(2..8),
+            IntervalSet::from_iter([0..2, 8..10])
+        );
+        assert_eq!(set.remove_range(0..10), IntervalSet::new());
+        assert_eq!(set.remove_range(4..6), set);
+        assert_eq!(set.remove_range(3..3), set);
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", IntervalSet::<()>::new()), "[]");
+        assert_eq!(
+            format!("{:?}", IntervalSet::from_iter([2..3, 0..1])),
+            "[0..1, 2..3]"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let set = IntervalSet::from_iter([0..1, 2..3]);
+
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            (&set).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -1,7 +1,49 @@
+pub mod bounded_map;
+pub mod bytes_trie_map;
+pub mod chain_vec;
+pub mod counter_map;
+pub mod default_map;
+pub mod env;
+pub mod finger_tree;
 pub mod flail_map;
+pub mod grid;
+pub mod history;
+pub mod indexed_map;
+pub mod interner;
+pub mod interval_set;
 pub mod list;
+pub mod lookup;
 pub mod map;
+pub mod map_by;
+pub mod min_stack;
+pub mod non_empty_list;
+pub mod ord_multi_map;
+pub mod ring_buffer;
+pub mod seq;
+pub mod small_map;
+pub mod symbol_table;
 
+pub use bounded_map::BoundedMap;
+pub use bytes_trie_map::BytesTrieMap;
+pub use chain_vec::ChainVec;
+pub use counter_map::CounterMap;
+pub use default_map::DefaultMap;
+pub use env::Env;
+pub use finger_tree::FingerTree;
 pub use flail_map::FlailMap;
+pub use grid::Grid;
+pub use history::History;
+pub use indexed_map::IndexedMap;
+pub use interner::Interner;
+pub use interval_set::IntervalSet;
 pub use list::List;
+pub use lookup::Lookup;
 pub use map::Map;
+pub use map_by::MapBy;
+pub use min_stack::MinStack;
+pub use non_empty_list::NonEmptyList;
+pub use ord_multi_map::OrdMultiMap;
+pub use ring_buffer::RingBuffer;
+pub use seq::Seq;
+pub use small_map::SmallMap;
+pub use symbol_table::SymbolTable;
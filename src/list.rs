@@ -1,10 +1,15 @@
+use crate::Map;
 use std::{
     borrow::Borrow,
+    cmp::Ordering,
+    collections::HashMap,
     fmt::{self, Debug, Formatter},
+    hash::Hash,
+    ops::Index,
     rc::Rc,
 };
 
-#[derive(Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Eq, PartialOrd, Ord)]
 pub struct List<T> {
     cons: Option<Rc<Cons<T>>>,
     size: usize,
@@ -24,6 +29,32 @@ impl<T> List<T> {
         }
     }
 
+    pub fn singleton(value: T) -> Self {
+        Self::new().push_front(value)
+    }
+
+    pub fn from_fn(count: usize, function: impl FnMut(usize) -> T) -> Self {
+        Self::new().push_front_iter(
+            (0..count)
+                .map(function)
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev(),
+        )
+    }
+
+    pub fn unfold<S>(seed: S, mut function: impl FnMut(S) -> Option<(T, S)>) -> Self {
+        let mut values = vec![];
+        let mut state = seed;
+
+        while let Some((value, next)) = function(state) {
+            values.push(value);
+            state = next;
+        }
+
+        Self::new().push_front_iter(values.into_iter().rev())
+    }
+
     pub fn len(&self) -> usize {
         self.size
     }
@@ -53,6 +84,10 @@ impl<T> List<T> {
         list
     }
 
+    pub fn prepend(&self, iterator: impl IntoIterator<Item = T>) -> Self {
+        self.push_front_iter(iterator.into_iter().collect::<Vec<_>>().into_iter().rev())
+    }
+
     pub fn pop_front(&self) -> Self {
         if let Some(cons) = &self.cons {
             Self {
@@ -64,12 +99,470 @@ impl<T> List<T> {
         }
     }
 
+    pub fn uncons(&self) -> Option<(&T, Self)> {
+        self.cons.as_ref().map(|cons| {
+            (
+                &cons.head,
+                Self {
+                    cons: cons.tail.clone(),
+                    size: self.size - 1,
+                },
+            )
+        })
+    }
+
+    pub fn split_first(&self) -> Option<(&T, Self)> {
+        self.uncons()
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.cons.as_ref().map(|cons| &cons.head)
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.into_iter().last()
+    }
+
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.cons, &other.cons) {
+            (Some(one), Some(other)) => Rc::ptr_eq(one, other),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.into_iter().nth(index)
+    }
+
+    pub fn skip_while(&self, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        let mut list = self.clone();
+
+        while let Some((value, tail)) = list.uncons() {
+            if !predicate(value) {
+                break;
+            }
+
+            list = tail;
+        }
+
+        list
+    }
+
+    pub fn map<U>(&self, function: impl FnMut(&T) -> U) -> List<U> {
+        List::new().push_front_iter(
+            self.into_iter()
+                .map(function)
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev(),
+        )
+    }
+
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.into_iter().position(predicate)
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<&T> {
+        self.into_iter().find(|value| predicate(value))
+    }
+
+    pub fn fold<A>(&self, initial: A, function: impl FnMut(A, &T) -> A) -> A {
+        self.iter().fold(initial, function)
+    }
+
+    pub fn rfold<A>(&self, initial: A, function: impl FnMut(A, &T) -> A) -> A {
+        self.iter_rev().fold(initial, function)
+    }
+
+    pub fn iter(&self) -> ListIterator<'_, T> {
+        self.into_iter()
+    }
+
+    pub fn iter_rev(&self) -> impl Iterator<Item = &T> {
+        self.iter().rev()
+    }
+
+    pub fn chunks(&self, size: usize) -> ChunkIterator<'_, T> {
+        assert!(size > 0, "chunk size must be non-zero");
+
+        ChunkIterator {
+            iterator: self.iter(),
+            size,
+        }
+    }
+
+    pub fn windows(&self, size: usize) -> WindowIterator<'_, T> {
+        assert!(size > 0, "window size must be non-zero");
+
+        WindowIterator {
+            iterator: self.iter(),
+            size,
+        }
+    }
+
+    pub fn suffix_at(&self, index: usize) -> Self {
+        let mut cons = &self.cons;
+
+        for _ in 0..index {
+            if let Some(current) = cons {
+                cons = &current.tail;
+            } else {
+                break;
+            }
+        }
+
+        Self {
+            cons: cons.clone(),
+            size: self.size.saturating_sub(index),
+        }
+    }
+
+    pub fn common_suffix(&self, other: &Self) -> Self {
+        let mut one = self.suffix_at(self.size.saturating_sub(other.size));
+        let mut other = other.suffix_at(other.size.saturating_sub(self.size));
+
+        while !one.ptr_eq(&other) {
+            one = one.pop_front();
+            other = other.pop_front();
+        }
+
+        one
+    }
+
+    pub fn last_n(&self, count: usize) -> Self {
+        self.suffix_at(self.size.saturating_sub(count))
+    }
+
+    pub fn try_map<U, E>(&self, function: impl FnMut(&T) -> Result<U, E>) -> Result<List<U>, E> {
+        Ok(List::new().push_front_iter(
+            self.iter()
+                .map(function)
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .rev(),
+        ))
+    }
+
     pub fn contains<S: Eq + ?Sized>(&self, value: &S) -> bool
     where
         T: Borrow<S>,
     {
         self.into_iter().any(|other| other.borrow() == value)
     }
+
+    fn split_prefix(&self, index: usize) -> (Vec<&T>, Self) {
+        let mut prefix = Vec::with_capacity(index.min(self.size));
+        let mut cons = &self.cons;
+
+        while prefix.len() < index {
+            if let Some(current) = cons {
+                prefix.push(&current.head);
+                cons = &current.tail;
+            } else {
+                break;
+            }
+        }
+
+        let size = self.size - prefix.len();
+
+        (
+            prefix,
+            Self {
+                cons: cons.clone(),
+                size,
+            },
+        )
+    }
+}
+
+impl<T: Clone> List<T> {
+    pub fn repeat(value: T, count: usize) -> Self {
+        Self::new().push_front_iter((0..count).map(|_| value.clone()))
+    }
+
+    pub fn iterate(seed: T, mut function: impl FnMut(&T) -> T, count: usize) -> Self {
+        let mut values = Vec::with_capacity(count);
+
+        if count > 0 {
+            values.push(seed);
+        }
+
+        while values.len() < count {
+            let value = function(&values[values.len() - 1]);
+            values.push(value);
+        }
+
+        Self::new().push_front_iter(values.into_iter().rev())
+    }
+
+    pub fn take_while(&self, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        Self::new().push_front_iter(
+            self.into_iter()
+                .take_while(|value| predicate(value))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .cloned(),
+        )
+    }
+
+    pub fn insert_at(&self, index: usize, value: T) -> Option<Self> {
+        if index > self.size {
+            return None;
+        }
+
+        let (prefix, suffix) = self.split_prefix(index);
+
+        Some(
+            suffix
+                .push_front(value)
+                .push_front_iter(prefix.into_iter().rev().cloned()),
+        )
+    }
+
+    pub fn remove_at(&self, index: usize) -> Option<(T, Self)> {
+        let (prefix, suffix) = self.split_prefix(index);
+        let (value, suffix) = suffix.uncons()?;
+
+        Some((
+            value.clone(),
+            suffix.push_front_iter(prefix.into_iter().rev().cloned()),
+        ))
+    }
+
+    pub fn update_at(&self, index: usize, value: T) -> Option<Self> {
+        if index >= self.size {
+            return None;
+        }
+
+        let (prefix, suffix) = self.split_prefix(index);
+
+        Some(
+            suffix
+                .pop_front()
+                .push_front(value)
+                .push_front_iter(prefix.into_iter().rev().cloned()),
+        )
+    }
+
+    pub fn filter(&self, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        let mut values = vec![];
+        let mut prefix_length = 0;
+        let mut suffix = self.clone();
+        let mut cons = &self.cons;
+        let mut size = self.size;
+
+        while let Some(current) = cons {
+            cons = &current.tail;
+            size -= 1;
+
+            if predicate(&current.head) {
+                values.push(&current.head);
+            } else {
+                prefix_length = values.len();
+                suffix = Self {
+                    cons: cons.clone(),
+                    size,
+                };
+            }
+        }
+
+        values.truncate(prefix_length);
+
+        suffix.push_front_iter(values.into_iter().rev().cloned())
+    }
+
+    pub fn sorted_by(&self, mut compare: impl FnMut(&T, &T) -> Ordering) -> Self {
+        self.sort_prefix_by(self.size, &mut compare)
+    }
+
+    pub fn sorted_by_key<K: Ord>(&self, mut key: impl FnMut(&T) -> K) -> Self {
+        self.sorted_by(|one, other| key(one).cmp(&key(other)))
+    }
+
+    pub fn partition(&self, mut predicate: impl FnMut(&T) -> bool) -> (Self, Self) {
+        let (matching, rest): (Vec<_>, Vec<_>) =
+            self.into_iter().partition(|value| predicate(value));
+
+        (
+            Self::new().push_front_iter(matching.into_iter().rev().cloned()),
+            Self::new().push_front_iter(rest.into_iter().rev().cloned()),
+        )
+    }
+
+    pub fn drop_last(&self, count: usize) -> Self {
+        let (prefix, _) = self.split_prefix(self.size.saturating_sub(count));
+
+        Self::new().push_front_iter(prefix.into_iter().rev().cloned())
+    }
+
+    pub fn group_by<K: Eq + Hash>(&self, mut key: impl FnMut(&T) -> K) -> Map<K, Self> {
+        let mut groups = HashMap::<K, Vec<&T>>::new();
+
+        for value in self {
+            groups.entry(key(value)).or_default().push(value);
+        }
+
+        groups
+            .into_iter()
+            .map(|(key, values)| {
+                (
+                    key,
+                    Self::new().push_front_iter(values.into_iter().rev().cloned()),
+                )
+            })
+            .collect()
+    }
+
+    pub fn truncate(&self, length: usize) -> Self {
+        if length >= self.size {
+            return self.clone();
+        }
+
+        let (prefix, _) = self.split_prefix(length);
+
+        Self::new().push_front_iter(prefix.into_iter().rev().cloned())
+    }
+
+    pub fn rotate_left(&self, count: usize) -> Self {
+        assert!(count <= self.size, "rotation in bounds");
+
+        let (prefix, suffix) = self.split_prefix(count);
+
+        suffix.append(&Self::new().push_front_iter(prefix.into_iter().rev().cloned()))
+    }
+
+    pub fn append(&self, other: &Self) -> Self {
+        other.push_front_iter(
+            self.into_iter()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .cloned(),
+        )
+    }
+
+    fn sort_prefix_by(&self, length: usize, compare: &mut impl FnMut(&T, &T) -> Ordering) -> Self {
+        if length == 0 {
+            Self::new()
+        } else if length == 1 {
+            Self::new().push_front(self.first().expect("non-empty list").clone())
+        } else {
+            let middle = length / 2;
+
+            Self::merge_by(
+                self.sort_prefix_by(middle, compare),
+                self.suffix_at(middle)
+                    .sort_prefix_by(length - middle, compare),
+                compare,
+            )
+        }
+    }
+
+    fn merge_by(
+        mut left: Self,
+        mut right: Self,
+        compare: &mut impl FnMut(&T, &T) -> Ordering,
+    ) -> Self {
+        let mut reversed = Self::new();
+
+        loop {
+            match (left.uncons(), right.uncons()) {
+                (Some((one, left_tail)), Some((other, right_tail))) => {
+                    if compare(other, one) == Ordering::Less {
+                        reversed = reversed.push_front(other.clone());
+                        right = right_tail;
+                    } else {
+                        reversed = reversed.push_front(one.clone());
+                        left = left_tail;
+                    }
+                }
+                (Some(_), None) => return left.push_front_iter(reversed),
+                (None, _) => return right.push_front_iter(reversed),
+            }
+        }
+    }
+}
+
+impl<T: Clone + Ord> List<T> {
+    pub fn sorted(&self) -> Self {
+        self.sorted_by(Ord::cmp)
+    }
+}
+
+impl<T: Clone> List<List<T>> {
+    pub fn flatten(&self) -> List<T> {
+        let mut lists = self.into_iter().collect::<Vec<_>>();
+        let mut list = lists.pop().cloned().unwrap_or_default();
+
+        for other in lists.into_iter().rev() {
+            list = other.append(&list);
+        }
+
+        list
+    }
+}
+
+impl<T: PartialEq> List<T> {
+    pub fn starts_with(&self, prefix: &Self) -> bool {
+        prefix.size <= self.size
+            && self
+                .into_iter()
+                .zip(prefix)
+                .all(|(one, other)| one == other)
+    }
+
+    pub fn ends_with(&self, suffix: &Self) -> bool {
+        if suffix.size > self.size {
+            return false;
+        }
+
+        let list = self.suffix_at(self.size - suffix.size);
+
+        match (&list.cons, &suffix.cons) {
+            (Some(one), Some(other)) if Rc::ptr_eq(one, other) => true,
+            _ => list.into_iter().eq(suffix),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.size != other.size {
+            return false;
+        }
+
+        let mut one = &self.cons;
+        let mut other = &other.cons;
+
+        while let (Some(one_cons), Some(other_cons)) = (one, other) {
+            if Rc::ptr_eq(one_cons, other_cons) {
+                return true;
+            } else if one_cons.head != other_cons.head {
+                return false;
+            }
+
+            one = &one_cons.tail;
+            other = &other_cons.tail;
+        }
+
+        true
+    }
+}
+
+impl<T> Index<usize> for List<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                index
+            )
+        })
+    }
 }
 
 impl<T> Clone for List<T> {
@@ -111,14 +604,22 @@ impl<T> FromIterator<T> for List<T> {
     }
 }
 
-pub struct ListIterator<'a, T>(&'a Option<Rc<Cons<T>>>);
+pub struct ListIterator<'a, T> {
+    cons: &'a Option<Rc<Cons<T>>>,
+    size: usize,
+    stack: Vec<&'a T>,
+}
 
 impl<'a, T> IntoIterator for &'a List<T> {
     type Item = &'a T;
     type IntoIter = ListIterator<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        ListIterator(&self.cons)
+        ListIterator {
+            cons: &self.cons,
+            size: self.size,
+            stack: vec![],
+        }
     }
 }
 
@@ -126,82 +627,232 @@ impl<'a, T> Iterator for ListIterator<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(cons) = self.0 {
-            self.0 = &cons.tail;
-
-            Some(&cons.head)
-        } else {
-            None
+        if self.size == 0 {
+            return None;
         }
-    }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let cons = self.cons.as_ref()?;
 
-    #[test]
-    fn new() {
-        List::<()>::new();
-    }
+        self.cons = &cons.tail;
+        self.size -= 1;
 
-    #[test]
-    fn equal() {
-        assert_ne!(List::new(), List::new().push_front(42));
-        assert_eq!(List::<()>::new(), List::new());
-        assert_eq!(List::new().push_front(42), List::new().push_front(42));
+        Some(&cons.head)
     }
 
-    #[test]
-    fn ord() {
-        assert!(List::new() < List::new().push_front(1));
-        assert!(List::new().push_front(1) < List::new().push_front(2));
-        assert!(List::new().push_front(1) < List::new().push_front(1).push_front(1));
-        assert!(List::new().push_front(1).push_front(1) < List::new().push_front(2).push_front(1));
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.size, Some(self.size))
     }
+}
 
-    #[test]
-    fn len() {
-        assert_eq!(List::<()>::new().len(), 0);
-        assert_eq!(List::new().push_front(42).len(), 1);
-        assert_eq!(List::new().push_front(42).push_front(42).len(), 2);
-    }
+impl<T> DoubleEndedIterator for ListIterator<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
 
-    #[test]
-    fn is_empty() {
-        assert!(List::<()>::new().is_empty());
-        assert!(!List::new().push_front(42).is_empty());
-    }
+        if self.stack.is_empty() {
+            let mut cons = self.cons;
 
-    #[test]
-    fn contains() {
-        assert!(List::new().push_front(1).push_front(2).contains(&2),);
-    }
+            while let Some(current) = cons {
+                self.stack.push(&current.head);
+                cons = &current.tail;
+            }
+        }
 
-    #[test]
-    fn push_front_iter() {
-        assert_eq!(
-            List::new().push_front(1).push_front(2),
-            List::new().push_front_iter([1, 2]),
-        );
-    }
+        self.size -= 1;
 
-    #[test]
-    fn into_iter() {
-        assert_eq!(
-            List::new()
-                .push_front(1)
-                .push_front(2)
-                .into_iter()
-                .copied()
-                .collect::<Vec<_>>(),
-            vec![2, 1]
-        );
+        self.stack.pop()
     }
+}
 
-    #[test]
-    fn from_iter() {
-        assert_eq!(
+impl<T> ExactSizeIterator for ListIterator<'_, T> {}
+
+impl<T> Clone for ListIterator<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            cons: self.cons,
+            size: self.size,
+            stack: self.stack.clone(),
+        }
+    }
+}
+
+pub struct ListIntoIterator<T> {
+    cons: Option<Rc<Cons<T>>>,
+    size: usize,
+    stack: Vec<Rc<Cons<T>>>,
+}
+
+impl<T: Clone> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = ListIntoIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ListIntoIterator {
+            cons: self.cons,
+            size: self.size,
+            stack: vec![],
+        }
+    }
+}
+
+impl<T: Clone> Iterator for ListIntoIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
+
+        let cons = self.cons.take()?;
+
+        self.size -= 1;
+
+        match Rc::try_unwrap(cons) {
+            Ok(cons) => {
+                self.cons = cons.tail;
+
+                Some(cons.head)
+            }
+            Err(cons) => {
+                self.cons = cons.tail.clone();
+
+                Some(cons.head.clone())
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.size, Some(self.size))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for ListIntoIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
+
+        if self.stack.is_empty() {
+            let mut cons = self.cons.clone();
+
+            while let Some(current) = cons {
+                cons = current.tail.clone();
+                self.stack.push(current);
+            }
+        }
+
+        self.size -= 1;
+
+        self.stack.pop().map(|cons| cons.head.clone())
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for ListIntoIterator<T> {}
+
+pub struct ChunkIterator<'a, T> {
+    iterator: ListIterator<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> Iterator for ChunkIterator<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.iterator.by_ref().take(self.size).collect::<Vec<_>>();
+
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+}
+
+pub struct WindowIterator<'a, T> {
+    iterator: ListIterator<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> Iterator for WindowIterator<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iterator.len() < self.size {
+            return None;
+        }
+
+        let window = self.iterator.clone().take(self.size).collect();
+        self.iterator.next();
+
+        Some(window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        List::<()>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_ne!(List::new(), List::new().push_front(42));
+        assert_eq!(List::<()>::new(), List::new());
+        assert_eq!(List::new().push_front(42), List::new().push_front(42));
+    }
+
+    #[test]
+    fn ord() {
+        assert!(List::new() < List::new().push_front(1));
+        assert!(List::new().push_front(1) < List::new().push_front(2));
+        assert!(List::new().push_front(1) < List::new().push_front(1).push_front(1));
+        assert!(List::new().push_front(1).push_front(1) < List::new().push_front(2).push_front(1));
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(List::<()>::new().len(), 0);
+        assert_eq!(List::new().push_front(42).len(), 1);
+        assert_eq!(List::new().push_front(42).push_front(42).len(), 2);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(List::<()>::new().is_empty());
+        assert!(!List::new().push_front(42).is_empty());
+    }
+
+    #[test]
+    fn contains() {
+        assert!(List::new().push_front(1).push_front(2).contains(&2),);
+    }
+
+    #[test]
+    fn push_front_iter() {
+        assert_eq!(
+            List::new().push_front(1).push_front(2),
+            List::new().push_front_iter([1, 2]),
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            (&List::new().push_front(1).push_front(2))
+                .into_iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+    }
+
+    #[test]
+    fn from_iter() {
+        assert_eq!(
             List::from_iter([1, 2]),
             List::new().push_front(1).push_front(2)
         );
@@ -219,4 +870,697 @@ mod tests {
             format!("{:?}", [1, 2])
         );
     }
+
+    #[test]
+    fn append() {
+        assert_eq!(List::<()>::new().append(&List::new()), List::new());
+        assert_eq!(
+            List::from_iter([2, 1]).append(&List::new()),
+            List::from_iter([2, 1])
+        );
+        assert_eq!(
+            List::new().append(&List::from_iter([2, 1])),
+            List::from_iter([2, 1])
+        );
+        assert_eq!(
+            List::from_iter([2, 1]).append(&List::from_iter([4, 3])),
+            List::from_iter([4, 3, 2, 1])
+        );
+    }
+
+    #[test]
+    fn append_shares_suffix() {
+        let list = List::from_iter([2, 1]);
+        let other = List::from_iter([3]).append(&list);
+
+        assert!(Rc::ptr_eq(
+            list.cons.as_ref().unwrap(),
+            other.cons.as_ref().unwrap().tail.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn get() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.get(0), Some(&1));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&3));
+        assert_eq!(list.get(3), None);
+        assert_eq!(List::<()>::new().get(0), None);
+    }
+
+    #[test]
+    fn first() {
+        assert_eq!(List::<()>::new().first(), None);
+        assert_eq!(List::from_iter([2, 1]).first(), Some(&1));
+    }
+
+    #[test]
+    fn last() {
+        assert_eq!(List::<()>::new().last(), None);
+        assert_eq!(List::from_iter([1]).last(), Some(&1));
+        assert_eq!(List::from_iter([2, 1]).last(), Some(&2));
+    }
+
+    #[test]
+    fn uncons() {
+        assert_eq!(List::<()>::new().uncons(), None);
+        assert_eq!(List::from_iter([1]).uncons(), Some((&1, List::new())));
+        assert_eq!(
+            List::from_iter([2, 1]).uncons(),
+            Some((&1, List::from_iter([2])))
+        );
+    }
+
+    #[test]
+    fn take_while() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.take_while(|&value| value < 3), List::from_iter([2, 1]));
+        assert_eq!(list.take_while(|_| true), list);
+        assert_eq!(list.take_while(|_| false), List::new());
+    }
+
+    #[test]
+    fn skip_while() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.skip_while(|&value| value < 3), List::from_iter([3]));
+        assert_eq!(list.skip_while(|_| true), List::new());
+        assert_eq!(list.skip_while(|_| false), list);
+    }
+
+    #[test]
+    fn insert_at() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.insert_at(0, 0), Some(List::from_iter([3, 2, 1, 0])));
+        assert_eq!(list.insert_at(1, 0), Some(List::from_iter([3, 2, 0, 1])));
+        assert_eq!(list.insert_at(3, 0), Some(List::from_iter([0, 3, 2, 1])));
+        assert_eq!(list.insert_at(4, 0), None);
+        assert_eq!(List::new().insert_at(0, 0), Some(List::from_iter([0])));
+    }
+
+    #[test]
+    fn insert_at_shares_suffix() {
+        let list = List::from_iter([3, 2, 1]);
+        let other = list.insert_at(1, 0).unwrap();
+
+        assert!(Rc::ptr_eq(
+            list.cons.as_ref().unwrap().tail.as_ref().unwrap(),
+            other
+                .cons
+                .as_ref()
+                .unwrap()
+                .tail
+                .as_ref()
+                .unwrap()
+                .tail
+                .as_ref()
+                .unwrap()
+        ));
+    }
+
+    #[test]
+    fn remove_at() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.remove_at(0), Some((1, List::from_iter([3, 2]))));
+        assert_eq!(list.remove_at(1), Some((2, List::from_iter([3, 1]))));
+        assert_eq!(list.remove_at(2), Some((3, List::from_iter([2, 1]))));
+        assert_eq!(list.remove_at(3), None);
+        assert_eq!(List::<()>::new().remove_at(0), None);
+    }
+
+    #[test]
+    fn update_at() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.update_at(0, 0), Some(List::from_iter([3, 2, 0])));
+        assert_eq!(list.update_at(2, 0), Some(List::from_iter([0, 2, 1])));
+        assert_eq!(list.update_at(3, 0), None);
+        assert_eq!(list, List::from_iter([3, 2, 1]));
+    }
+
+    #[test]
+    fn map() {
+        assert_eq!(List::<usize>::new().map(|value| value * 2), List::new());
+        assert_eq!(
+            List::from_iter([3, 2, 1]).map(|value| value * 2),
+            List::from_iter([6, 4, 2])
+        );
+        assert_eq!(
+            List::from_iter([2, 1]).map(|value| value.to_string()),
+            List::from_iter(["2".to_string(), "1".to_string()])
+        );
+    }
+
+    #[test]
+    fn filter() {
+        let list = List::from_iter([4, 3, 2, 1]);
+
+        assert_eq!(list.filter(|value| value % 2 == 0), List::from_iter([4, 2]));
+        assert_eq!(list.filter(|_| true), list);
+        assert_eq!(list.filter(|_| false), List::new());
+        assert_eq!(list.filter(|&value| value != 1), List::from_iter([4, 3, 2]));
+        assert_eq!(list.filter(|&value| value != 4), List::from_iter([3, 2, 1]));
+    }
+
+    #[test]
+    fn filter_shares_suffix() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert!(Rc::ptr_eq(
+            list.filter(|&value| value != 1).cons.as_ref().unwrap(),
+            list.cons.as_ref().unwrap().tail.as_ref().unwrap()
+        ));
+        assert!(Rc::ptr_eq(
+            list.filter(|_| true).cons.as_ref().unwrap(),
+            list.cons.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn sorted() {
+        assert_eq!(List::<usize>::new().sorted(), List::new());
+        assert_eq!(List::from_iter([1]).sorted(), List::from_iter([1]));
+        assert_eq!(
+            List::from_iter([1, 3, 2, 5, 4]).sorted(),
+            List::from_iter([5, 4, 3, 2, 1])
+        );
+        assert_eq!(
+            List::from_iter([3, 1, 2, 1]).sorted(),
+            List::from_iter([3, 2, 1, 1])
+        );
+    }
+
+    #[test]
+    fn sorted_many() {
+        let values = (0..100).map(|value| (value * 37) % 101).collect::<Vec<_>>();
+        let mut sorted = values.clone();
+        sorted.sort();
+
+        assert_eq!(
+            List::from_iter(values).sorted(),
+            List::from_iter(sorted.into_iter().rev())
+        );
+    }
+
+    #[test]
+    fn sorted_by() {
+        assert_eq!(
+            List::from_iter([1, 3, 2]).sorted_by(|one, other| other.cmp(one)),
+            List::from_iter([1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn sorted_by_key() {
+        assert_eq!(
+            List::from_iter([(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')])
+                .sorted_by_key(|(key, _)| *key),
+            List::from_iter([(1, 'a'), (1, 'c'), (0, 'b'), (0, 'd')])
+        );
+    }
+
+    #[test]
+    fn partition() {
+        assert_eq!(
+            List::from_iter([4, 3, 2, 1]).partition(|value| value % 2 == 0),
+            (List::from_iter([4, 2]), List::from_iter([3, 1]))
+        );
+        assert_eq!(
+            List::<usize>::new().partition(|_| true),
+            (List::new(), List::new())
+        );
+    }
+
+    #[test]
+    fn flatten() {
+        assert_eq!(List::<List<()>>::new().flatten(), List::new());
+        assert_eq!(
+            List::from_iter([
+                List::from_iter([5, 4]),
+                List::new(),
+                List::from_iter([3]),
+                List::from_iter([2, 1]),
+            ])
+            .flatten(),
+            List::from_iter([5, 4, 3, 2, 1])
+        );
+    }
+
+    #[test]
+    fn flatten_shares_last() {
+        let list = List::from_iter([2, 1]);
+
+        assert!(Rc::ptr_eq(
+            List::from_iter([list.clone(), List::from_iter([3])])
+                .flatten()
+                .cons
+                .as_ref()
+                .unwrap()
+                .tail
+                .as_ref()
+                .unwrap(),
+            list.cons.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn position() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.position(|&value| value == 1), Some(0));
+        assert_eq!(list.position(|&value| value > 1), Some(1));
+        assert_eq!(list.position(|&value| value > 3), None);
+    }
+
+    #[test]
+    fn find() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.find(|&value| value > 1), Some(&2));
+        assert_eq!(list.find(|&value| value > 3), None);
+    }
+
+    #[test]
+    fn starts_with() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert!(list.starts_with(&List::new()));
+        assert!(list.starts_with(&List::from_iter([2, 1])));
+        assert!(list.starts_with(&list));
+        assert!(!list.starts_with(&List::from_iter([3, 1])));
+        assert!(!list.starts_with(&List::from_iter([4, 3, 2, 1])));
+    }
+
+    #[test]
+    fn ends_with() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert!(list.ends_with(&List::new()));
+        assert!(list.ends_with(&List::from_iter([3, 2])));
+        assert!(list.ends_with(&list));
+        assert!(list.ends_with(&list.pop_front()));
+        assert!(!list.ends_with(&List::from_iter([3, 1])));
+        assert!(!list.ends_with(&List::from_iter([4, 3, 2, 1])));
+    }
+
+    #[test]
+    fn into_iter_owned() {
+        assert_eq!(
+            List::from_iter([3, 2, 1]).into_iter().collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn into_iter_owned_shared() {
+        let list = List::from_iter([2, 1]);
+        let other = list.push_front(0);
+
+        assert_eq!(other.into_iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn into_iter_owned_unique() {
+        let value = Rc::new(42);
+        let list = List::new().push_front(value.clone());
+
+        assert_eq!(Rc::strong_count(&value), 2);
+
+        let values = list.into_iter().collect::<Vec<_>>();
+
+        assert_eq!(Rc::strong_count(&value), 2);
+        assert_eq!(values, vec![value]);
+    }
+
+    #[test]
+    fn split_first() {
+        assert_eq!(List::<()>::new().split_first(), None);
+        assert_eq!(
+            List::from_iter([2, 1]).split_first(),
+            Some((&1, List::from_iter([2])))
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let list = List::from_iter([1, 2, 3]);
+
+        assert_eq!(
+            list.iter().collect::<Vec<_>>(),
+            (&list).into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn size_hint() {
+        let list = List::from_iter([3, 2, 1]);
+        let mut iterator = list.iter();
+
+        assert_eq!(iterator.len(), 3);
+        assert_eq!(iterator.size_hint(), (3, Some(3)));
+
+        iterator.next();
+
+        assert_eq!(iterator.len(), 2);
+
+        iterator.next();
+        iterator.next();
+        iterator.next();
+
+        assert_eq!(iterator.len(), 0);
+    }
+
+    #[test]
+    fn size_hint_owned() {
+        let mut iterator = List::from_iter([2, 1]).into_iter();
+
+        assert_eq!(iterator.len(), 2);
+
+        iterator.next();
+
+        assert_eq!(iterator.size_hint(), (1, Some(1)));
+    }
+
+    #[test]
+    fn index() {
+        let list = List::from_iter([2, 1]);
+
+        assert_eq!(list[0], 1);
+        assert_eq!(list[1], 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+    fn index_out_of_bounds() {
+        let _ = List::from_iter([2, 1])[2];
+    }
+
+    #[test]
+    fn chunks() {
+        assert_eq!(List::<()>::new().chunks(2).count(), 0);
+        assert_eq!(
+            List::from_iter([5, 4, 3, 2, 1])
+                .chunks(2)
+                .collect::<Vec<_>>(),
+            vec![vec![&1, &2], vec![&3, &4], vec![&5]]
+        );
+        assert_eq!(
+            List::from_iter([2, 1]).chunks(3).collect::<Vec<_>>(),
+            vec![vec![&1, &2]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_zero() {
+        List::<()>::new().chunks(0);
+    }
+
+    #[test]
+    fn windows() {
+        assert_eq!(List::<()>::new().windows(2).count(), 0);
+        assert_eq!(
+            List::from_iter([4, 3, 2, 1]).windows(2).collect::<Vec<_>>(),
+            vec![vec![&1, &2], vec![&2, &3], vec![&3, &4]]
+        );
+        assert_eq!(
+            List::from_iter([2, 1]).windows(2).collect::<Vec<_>>(),
+            vec![vec![&1, &2]]
+        );
+        assert_eq!(List::from_iter([2, 1]).windows(3).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn windows_zero() {
+        List::<()>::new().windows(0);
+    }
+
+    #[test]
+    fn last_n() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.last_n(0), List::new());
+        assert_eq!(list.last_n(2), List::from_iter([3, 2]));
+        assert_eq!(list.last_n(3), list);
+        assert_eq!(list.last_n(4), list);
+        assert!(Rc::ptr_eq(
+            list.last_n(2).cons.as_ref().unwrap(),
+            list.cons.as_ref().unwrap().tail.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn drop_last() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.drop_last(0), list);
+        assert_eq!(list.drop_last(1), List::from_iter([2, 1]));
+        assert_eq!(list.drop_last(3), List::new());
+        assert_eq!(list.drop_last(4), List::new());
+    }
+
+    #[test]
+    fn try_map() {
+        assert_eq!(
+            List::from_iter([3, 2, 1]).try_map(|&value| Ok::<_, ()>(value * 2)),
+            Ok(List::from_iter([6, 4, 2]))
+        );
+        assert_eq!(
+            List::from_iter([3, 2, 1]).try_map(|&value| if value < 2 {
+                Ok(value)
+            } else {
+                Err(value)
+            }),
+            Err(2)
+        );
+    }
+
+    #[test]
+    fn suffix_at() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.suffix_at(0), list);
+        assert_eq!(list.suffix_at(1), List::from_iter([3, 2]));
+        assert_eq!(list.suffix_at(1).len(), 2);
+        assert_eq!(list.suffix_at(3), List::new());
+        assert_eq!(list.suffix_at(4), List::new());
+        assert!(Rc::ptr_eq(
+            list.suffix_at(1).cons.as_ref().unwrap(),
+            list.cons.as_ref().unwrap().tail.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn ptr_eq() {
+        let list = List::from_iter([2, 1]);
+
+        assert!(List::<()>::new().ptr_eq(&List::new()));
+        assert!(list.ptr_eq(&list.clone()));
+        assert!(list.pop_front().ptr_eq(&list.suffix_at(1)));
+        assert!(!list.ptr_eq(&List::from_iter([2, 1])));
+        assert!(!list.ptr_eq(&List::new()));
+    }
+
+    #[test]
+    fn equal_shared_suffix() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.push_front(0), list.push_front(0));
+        assert_ne!(list.push_front(0), list.push_front(1));
+        assert_ne!(list.push_front(0), List::from_iter([4, 3, 2, 0]));
+        assert_eq!(list.push_front(0), List::from_iter([3, 2, 1, 0]));
+    }
+
+    #[test]
+    fn common_suffix() {
+        let list = List::from_iter([2, 1]);
+        let one = list.push_front(3).push_front(4);
+        let other = list.push_front(5);
+
+        assert!(one.common_suffix(&other).ptr_eq(&list));
+        assert!(other.common_suffix(&one).ptr_eq(&list));
+        assert!(one.common_suffix(&list).ptr_eq(&list));
+        assert!(one.common_suffix(&one).ptr_eq(&one));
+        assert_eq!(
+            List::from_iter([2, 1]).common_suffix(&List::from_iter([2, 1])),
+            List::new()
+        );
+        assert_eq!(List::<()>::new().common_suffix(&List::new()), List::new());
+    }
+
+    #[test]
+    fn iter_rev() {
+        assert_eq!(List::<()>::new().iter_rev().count(), 0);
+        assert_eq!(
+            List::from_iter([1, 2, 3]).iter_rev().collect::<Vec<_>>(),
+            vec![&1, &2, &3]
+        );
+    }
+
+    #[test]
+    fn repeat() {
+        assert_eq!(List::repeat(42, 0), List::new());
+        assert_eq!(List::repeat(42, 3), List::from_iter([42, 42, 42]));
+    }
+
+    #[test]
+    fn from_fn() {
+        assert_eq!(List::from_fn(0, |index| index), List::new());
+        assert_eq!(
+            List::from_fn(3, |index| index * 2),
+            List::from_iter([4, 2, 0])
+        );
+    }
+
+    #[test]
+    fn iterate() {
+        assert_eq!(List::iterate(1, |value| value * 2, 0), List::new());
+        assert_eq!(
+            List::iterate(1, |value| value * 2, 4),
+            List::from_iter([8, 4, 2, 1])
+        );
+    }
+
+    #[test]
+    fn iterate_calls() {
+        let mut calls = 0;
+
+        List::iterate(
+            1,
+            |value| {
+                calls += 1;
+                value + 1
+            },
+            4,
+        );
+
+        assert_eq!(calls, 3);
+
+        List::iterate(1, |_| panic!(), 1);
+        List::iterate(1, |_| panic!(), 0);
+    }
+
+    #[test]
+    fn unfold() {
+        assert_eq!(List::<()>::unfold((), |_| None), List::new());
+        assert_eq!(
+            List::unfold(1, |value| (value <= 3).then_some((value * 10, value + 1))),
+            List::from_iter([30, 20, 10])
+        );
+    }
+
+    #[test]
+    fn truncate() {
+        let list = List::from_iter([3, 2, 1]);
+
+        assert_eq!(list.truncate(0), List::new());
+        assert_eq!(list.truncate(2), List::from_iter([2, 1]));
+        assert!(list.truncate(3).ptr_eq(&list));
+        assert!(list.truncate(4).ptr_eq(&list));
+    }
+
+    #[test]
+    fn rotate_left() {
+        let list = List::from_iter([4, 3, 2, 1]);
+
+        assert_eq!(list.rotate_left(0), list);
+        assert_eq!(list.rotate_left(1), List::from_iter([1, 4, 3, 2]));
+        assert_eq!(list.rotate_left(3), List::from_iter([3, 2, 1, 4]));
+        assert_eq!(list.rotate_left(4), list);
+        assert_eq!(List::<()>::new().rotate_left(0), List::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotate_left_out_of_bounds() {
+        List::from_iter([1]).rotate_left(2);
+    }
+
+    #[test]
+    fn group_by() {
+        let map = List::from_iter([5, 4, 3, 2, 1]).group_by(|value| value % 2);
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&0), Some(&List::from_iter([4, 2])));
+        assert_eq!(map.get(&1), Some(&List::from_iter([5, 3, 1])));
+        assert!(List::<usize>::new().group_by(|value| *value).is_empty());
+    }
+
+    #[test]
+    fn fold() {
+        assert_eq!(List::<usize>::new().fold(0, |sum, value| sum + value), 0);
+        assert_eq!(
+            List::from_iter([3, 2, 1]).fold(vec![], |mut values, value| {
+                values.push(*value);
+                values
+            }),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn rfold() {
+        assert_eq!(List::<usize>::new().rfold(0, |sum, value| sum + value), 0);
+        assert_eq!(
+            List::from_iter([3, 2, 1]).rfold(vec![], |mut values, value| {
+                values.push(*value);
+                values
+            }),
+            vec![3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn prepend() {
+        let list = List::new().push_front(4);
+
+        assert_eq!(list.prepend([]), list);
+        assert_eq!(
+            list.prepend([1, 2, 3]).iter().collect::<Vec<_>>(),
+            vec![&1, &2, &3, &4]
+        );
+        assert!(list.prepend([1, 2, 3]).suffix_at(3).ptr_eq(&list));
+    }
+
+    #[test]
+    fn iter_next_back() {
+        let list = List::from_iter([4, 3, 2, 1]);
+        let mut iterator = list.iter();
+
+        assert_eq!(iterator.next_back(), Some(&4));
+        assert_eq!(iterator.next(), Some(&1));
+        assert_eq!(iterator.next_back(), Some(&3));
+        assert_eq!(iterator.len(), 1);
+        assert_eq!(iterator.next(), Some(&2));
+        assert_eq!(iterator.next_back(), None);
+        assert_eq!(iterator.next(), None);
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![&4, &3, &2, &1]);
+    }
+
+    #[test]
+    fn into_iter_next_back() {
+        let list = List::from_iter([4, 3, 2, 1]);
+        let mut iterator = list.clone().into_iter();
+
+        assert_eq!(iterator.next_back(), Some(4));
+        assert_eq!(iterator.next(), Some(1));
+        assert_eq!(iterator.next_back(), Some(3));
+        assert_eq!(iterator.next(), Some(2));
+        assert_eq!(iterator.next_back(), None);
+        assert_eq!(iterator.next(), None);
+        assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn singleton() {
+        assert_eq!(List::singleton(1), List::new().push_front(1));
+        assert_eq!(List::singleton(1).len(), 1);
+    }
 }
@@ -0,0 +1,106 @@
+use std::{
+    borrow::Borrow,
+    collections::{btree_map, hash_map, BTreeMap, HashMap},
+    hash::Hash,
+};
+
+pub trait Lookup<K, V, Q: ?Sized = K> {
+    type Iterator<'a>: Iterator<Item = (&'a K, &'a V)>
+    where
+        Self: 'a,
+        K: 'a,
+        V: 'a;
+
+    fn get(&self, key: &Q) -> Option<&V>;
+
+    fn iter(&self) -> Self::Iterator<'_>;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Eq + Hash + Borrow<Q>, V, Q: Eq + Hash + ?Sized> Lookup<K, V, Q> for HashMap<K, V> {
+    type Iterator<'a>
+        = hash_map::Iter<'a, K, V>
+    where
+        Self: 'a,
+        K: 'a,
+        V: 'a;
+
+    fn get(&self, key: &Q) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        HashMap::iter(self)
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+}
+
+impl<K: Ord + Borrow<Q>, V, Q: Ord + ?Sized> Lookup<K, V, Q> for BTreeMap<K, V> {
+    type Iterator<'a>
+        = btree_map::Iter<'a, K, V>
+    where
+        Self: 'a,
+        K: 'a,
+        V: 'a;
+
+    fn get(&self, key: &Q) -> Option<&V> {
+        BTreeMap::get(self, key)
+    }
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        BTreeMap::iter(self)
+    }
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup<H: Lookup<usize, usize>>(map: &H) -> (Option<&usize>, usize, bool) {
+        (map.get(&1), map.len(), map.is_empty())
+    }
+
+    #[test]
+    fn hash_map() {
+        assert_eq!(lookup(&HashMap::new()), (None, 0, true));
+        assert_eq!(lookup(&HashMap::from([(1, 2)])), (Some(&2), 1, false));
+        assert_eq!(
+            Lookup::<usize, usize>::iter(&HashMap::from([(1, 2)])).collect::<Vec<_>>(),
+            vec![(&1, &2)]
+        );
+    }
+
+    #[test]
+    fn btree_map() {
+        assert_eq!(lookup(&BTreeMap::new()), (None, 0, true));
+        assert_eq!(lookup(&BTreeMap::from([(1, 2)])), (Some(&2), 1, false));
+        assert_eq!(
+            Lookup::<usize, usize>::iter(&BTreeMap::from([(2, 3), (1, 2)])).collect::<Vec<_>>(),
+            vec![(&1, &2), (&2, &3)]
+        );
+    }
+
+    #[test]
+    fn borrowed_key() {
+        assert_eq!(
+            Lookup::<String, usize, str>::get(&HashMap::from([("foo".to_string(), 1)]), "foo"),
+            Some(&1)
+        );
+        assert_eq!(
+            Lookup::<String, usize, str>::get(&BTreeMap::from([("foo".to_string(), 1)]), "foo"),
+            Some(&1)
+        );
+    }
+}
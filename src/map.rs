@@ -1,19 +1,35 @@
 use crate::{list, List};
 use std::{
     borrow::Borrow,
-    collections::{HashMap, HashSet},
-    fmt::{self, Debug, Formatter},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
     hash::Hash,
-    ops::Index,
+    ops::{Index, RangeBounds},
+    vec,
 };
 
 pub struct Map<K, V>(List<(K, V)>);
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OccupiedError<K, V> {
+    key: K,
+    value: V,
+}
+
 impl<K, V> Map<K, V> {
     pub fn new() -> Self {
         Self(Default::default())
     }
 
+    pub fn singleton(key: K, value: V) -> Self {
+        Self::new().insert(key, value)
+    }
+
+    pub fn raw_len(&self) -> usize {
+        self.0.len()
+    }
+
     pub fn get<Q: Eq + ?Sized>(&self, key: &Q) -> Option<&V>
     where
         K: Borrow<Q>,
@@ -27,6 +43,42 @@ impl<K, V> Map<K, V> {
         })
     }
 
+    pub fn get_many<Q: Eq + ?Sized, const N: usize>(&self, keys: [&Q; N]) -> [Option<&V>; N]
+    where
+        K: Borrow<Q>,
+    {
+        let mut values = [None; N];
+        let mut remaining = N;
+
+        for (key, value) in &self.0 {
+            if remaining == 0 {
+                break;
+            }
+
+            for (index, &other) in keys.iter().enumerate() {
+                if values[index].is_none() && key.borrow() == other {
+                    values[index] = Some(value);
+                    remaining -= 1;
+                }
+            }
+        }
+
+        values
+    }
+
+    pub fn history<'a, Q: Eq + ?Sized>(&'a self, key: &'a Q) -> impl Iterator<Item = &'a V>
+    where
+        K: Borrow<Q>,
+    {
+        self.0.iter().filter_map(move |(other_key, value)| {
+            if other_key.borrow() == key {
+                Some(value)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn insert(&self, key: K, value: V) -> Self {
         Self(self.0.push_front((key, value)))
     }
@@ -34,6 +86,12 @@ impl<K, V> Map<K, V> {
     pub fn insert_iter(&self, iterator: impl IntoIterator<Item = (K, V)>) -> Self {
         Self(self.0.push_front_iter(iterator))
     }
+
+    pub fn pop(&self) -> Option<((&K, &V), Self)> {
+        let ((key, value), rest) = self.0.uncons()?;
+
+        Some(((key, value), Self(rest)))
+    }
 }
 
 impl<K: Eq + Hash, V> Map<K, V> {
@@ -58,6 +116,51 @@ impl<K: Eq + Hash, V> Map<K, V> {
         self.keys().any(|other| other.borrow() == key)
     }
 
+    pub fn entry(&self, key: K) -> Entry<K, V> {
+        if self.contains_key(&key) {
+            Entry::Occupied(OccupiedEntry {
+                map: self.clone(),
+                key,
+            })
+        } else {
+            Entry::Vacant(VacantEntry {
+                map: self.clone(),
+                key,
+            })
+        }
+    }
+
+    pub fn replace(&self, key: K, value: V) -> (Option<&V>, Self) {
+        (self.get(&key), self.insert(key, value))
+    }
+
+    pub fn try_insert(&self, key: K, value: V) -> Result<Self, OccupiedError<K, V>> {
+        if self.contains_key(&key) {
+            Err(OccupiedError { key, value })
+        } else {
+            Ok(self.insert(key, value))
+        }
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        let keys = other.keys().collect::<HashSet<_>>();
+
+        !self.keys().any(|key| keys.contains(key))
+    }
+
+    pub fn iter(&self) -> MapIterator<'_, K, V> {
+        self.into_iter()
+    }
+
+    pub fn shadowed(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut set = HashSet::new();
+
+        self.0
+            .iter()
+            .filter(move |(key, _)| !set.insert(key))
+            .map(|(key, value)| (key, value))
+    }
+
     pub fn keys(&self) -> impl Iterator<Item = &K> {
         self.into_iter().map(|(key, _)| key)
     }
@@ -67,6 +170,307 @@ impl<K: Eq + Hash, V> Map<K, V> {
     }
 }
 
+impl<K: Eq + Hash + Ord, V> Map<K, V> {
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut entries = self.into_iter().collect::<Vec<_>>();
+
+        entries.sort_by_key(|(key, _)| *key);
+
+        entries.into_iter()
+    }
+
+    pub fn iter_range<'a>(
+        &'a self,
+        range: impl RangeBounds<K> + 'a,
+    ) -> impl Iterator<Item = (&'a K, &'a V)> {
+        self.into_iter().filter(move |(key, _)| range.contains(key))
+    }
+}
+
+impl<K: Borrow<str> + Eq + Hash, V> Map<K, V> {
+    pub fn iter_prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = (&'a K, &'a V)> {
+        self.into_iter()
+            .filter(move |(key, _)| (*key).borrow().starts_with(prefix))
+    }
+}
+
+impl<K: Clone + Eq + Hash + Ord, V: Clone> Map<K, V> {
+    /// Returns visible entries in ascending key order.
+    pub fn to_sorted_vec(&self) -> Vec<(K, V)> {
+        self.iter_sorted()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+}
+
+impl<K: Debug + Eq + Hash, V: Debug + PartialEq> Map<K, V> {
+    pub fn diff_display<'a>(&'a self, other: &'a Self) -> impl Display + 'a {
+        MapDiff { map: self, other }
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq> Map<K, V> {
+    pub fn contains_entry<Q: Eq + ?Sized>(&self, key: &Q, value: &V) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.get(key) == Some(value)
+    }
+
+    pub fn is_submap_of(&self, other: &Self) -> bool {
+        let entries = other.into_iter().collect::<HashMap<_, _>>();
+
+        self.into_iter()
+            .all(|(key, value)| entries.get(key) == Some(&value))
+    }
+
+    pub fn is_supermap_of(&self, other: &Self) -> bool {
+        other.is_submap_of(self)
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Map<K, V> {
+    pub fn union(&self, other: &Self, mut resolve: impl FnMut(&K, &V, &V) -> V) -> Self {
+        let entries = self.into_iter().collect::<HashMap<_, _>>();
+
+        self.insert_iter(
+            other
+                .into_iter()
+                .map(|(key, value)| {
+                    (
+                        key.clone(),
+                        if let Some(&current) = entries.get(key) {
+                            resolve(key, current, value)
+                        } else {
+                            value.clone()
+                        },
+                    )
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    pub fn left_union(&self, other: &Self) -> Self {
+        self.union(other, |_, value, _| value.clone())
+    }
+
+    pub fn right_union(&self, other: &Self) -> Self {
+        self.union(other, |_, _, value| value.clone())
+    }
+
+    pub fn into_hash_map(self) -> HashMap<K, V> {
+        self.into_iter().collect()
+    }
+
+    /// Returns visible entries in iteration order, most recently inserted first.
+    pub fn to_vec(&self) -> Vec<(K, V)> {
+        self.into_iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
+    pub fn get_or_insert_with(&self, key: K, function: impl FnOnce() -> V) -> (V, Self) {
+        if let Some(value) = self.get(&key) {
+            (value.clone(), self.clone())
+        } else {
+            let value = function();
+
+            (value.clone(), self.insert(key, value))
+        }
+    }
+
+    pub fn update_many<F: FnOnce(Option<&V>) -> Option<V>>(
+        &self,
+        updates: impl IntoIterator<Item = (K, F)>,
+    ) -> Self {
+        let updates = updates.into_iter().collect::<Vec<_>>();
+        let mut entries = updates
+            .iter()
+            .map(|(key, _)| (key.clone(), (None, None)))
+            .collect::<HashMap<K, (Option<&V>, Option<usize>)>>();
+
+        for (index, (key, value)) in self.0.iter().enumerate() {
+            if let Some((current, last)) = entries.get_mut(key) {
+                current.get_or_insert(value);
+                *last = Some(index);
+            }
+        }
+
+        let mut keys = vec![];
+        let mut values = HashMap::<K, Option<V>>::new();
+
+        for (key, function) in updates {
+            let value = function(match values.get(&key) {
+                Some(value) => value.as_ref(),
+                None => entries[&key].0,
+            });
+
+            if values.insert(key.clone(), value).is_none() {
+                keys.push(key);
+            }
+        }
+
+        let removed = keys
+            .iter()
+            .filter(|key| values[*key].is_none())
+            .collect::<HashSet<_>>();
+
+        Self(
+            match removed.iter().filter_map(|key| entries[*key].1).max() {
+                Some(index) => self.0.suffix_at(index + 1).prepend(
+                    self.0
+                        .iter()
+                        .take(index + 1)
+                        .filter(|(key, _)| !removed.contains(key))
+                        .cloned(),
+                ),
+                None => self.0.clone(),
+            }
+            .push_front_iter(keys.into_iter().filter_map(|key| {
+                let value = values.remove(&key)??;
+
+                Some((key, value))
+            })),
+        )
+    }
+
+    /// Merges maps in order, with entries of later maps taking precedence.
+    pub fn merge_many(maps: impl IntoIterator<Item = Self>) -> Self {
+        maps.into_iter().fold(Self::new(), |map, other| {
+            map.insert_iter(other.to_vec().into_iter().rev())
+        })
+    }
+
+    pub fn join<W: Clone>(&self, other: &Map<K, W>) -> Map<K, (V, W)> {
+        let entries = other.into_iter().collect::<HashMap<_, _>>();
+
+        Map(
+            List::new().prepend(self.into_iter().filter_map(|(key, value)| {
+                Some((key.clone(), (value.clone(), (*entries.get(key)?).clone())))
+            })),
+        )
+    }
+
+    pub fn left_join<W: Clone>(&self, other: &Map<K, W>) -> Map<K, (V, Option<W>)> {
+        let entries = other.into_iter().collect::<HashMap<_, _>>();
+
+        Map(List::new().prepend(self.into_iter().map(|(key, value)| {
+            (
+                key.clone(),
+                (value.clone(), entries.get(key).map(|&value| value.clone())),
+            )
+        })))
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let keys = other.keys().collect::<HashSet<_>>();
+
+        self.retain(|key, _| !keys.contains(key))
+    }
+
+    pub fn remove_many<'a, Q: Eq + Hash + ?Sized + 'a>(
+        &self,
+        keys: impl IntoIterator<Item = &'a Q>,
+    ) -> Self
+    where
+        K: Borrow<Q>,
+    {
+        let keys = keys.into_iter().collect::<HashSet<_>>();
+
+        self.retain(|key, _| !keys.contains(key.borrow()))
+    }
+
+    pub fn rename_key<Q: Eq + ?Sized>(&self, old: &Q, new: K) -> Option<Self>
+    where
+        K: Borrow<Q>,
+    {
+        let mut entry = None;
+
+        for (index, (key, value)) in self.0.iter().enumerate() {
+            if key.borrow() == old {
+                entry = Some((entry.map_or(value, |(value, _)| value), index));
+            }
+        }
+
+        let (value, index) = entry?;
+
+        Some(Self(
+            self.0
+                .suffix_at(index + 1)
+                .prepend(
+                    self.0
+                        .iter()
+                        .take(index)
+                        .filter(|(key, _)| key.borrow() != old)
+                        .cloned(),
+                )
+                .push_front((new, value.clone())),
+        ))
+    }
+
+    pub fn retain_keys(&self, keys: &HashSet<K>) -> Self {
+        self.retain(|key, _| keys.contains(key))
+    }
+
+    pub fn without_keys(&self, keys: &HashSet<K>) -> Self {
+        self.retain(|key, _| !keys.contains(key))
+    }
+
+    pub fn retain(&self, mut predicate: impl FnMut(&K, &V) -> bool) -> Self {
+        Self(
+            List::new().prepend(
+                self.into_iter()
+                    .filter(|(key, value)| predicate(key, value))
+                    .map(|(key, value)| (key.clone(), value.clone())),
+            ),
+        )
+    }
+
+    pub fn filter(&self, predicate: impl FnMut(&K, &V) -> bool) -> Self {
+        self.retain(predicate)
+    }
+
+    pub fn partition(&self, mut predicate: impl FnMut(&K, &V) -> bool) -> (Self, Self) {
+        let (left, right) = self
+            .into_iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .partition::<Vec<_>, _>(|(key, value)| predicate(key, value));
+
+        (
+            Self(List::new().prepend(left)),
+            Self(List::new().prepend(right)),
+        )
+    }
+
+    pub fn intersection(&self, other: &Self, mut combine: impl FnMut(&K, &V, &V) -> V) -> Self {
+        let entries = other.into_iter().collect::<HashMap<_, _>>();
+
+        Self(
+            List::new().prepend(self.into_iter().filter_map(|(key, value)| {
+                Some((key.clone(), combine(key, value, entries.get(key)?)))
+            })),
+        )
+    }
+}
+
+impl<K, V> OccupiedError<K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+}
+
+impl<K: Debug, V> Display for OccupiedError<K, V> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "key {:?} already exists", self.key)
+    }
+}
+
+impl<K: Debug, V: Debug> Error for OccupiedError<K, V> {}
+
 impl<Q: Eq + ?Sized, K: Eq, V> Index<&Q> for Map<K, V>
 where
     K: Borrow<Q>,
@@ -128,15 +532,160 @@ impl<K: Eq + Hash, V: PartialEq> PartialEq for Map<K, V> {
     }
 }
 
+struct MapDiff<'a, K, V> {
+    map: &'a Map<K, V>,
+    other: &'a Map<K, V>,
+}
+
+impl<K: Debug + Eq + Hash, V: Debug + PartialEq> Display for MapDiff<'_, K, V> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let entries = self.other.into_iter().collect::<HashMap<_, _>>();
+        let mut lines = vec![];
+
+        for (key, value) in self.map {
+            match entries.get(key) {
+                None => lines.push(format!("- {:?}: {:?}", key, value)),
+                Some(&other) if other != value => {
+                    lines.push(format!("~ {:?}: {:?} -> {:?}", key, value, other))
+                }
+                Some(_) => {}
+            }
+        }
+
+        for (key, value) in self.other {
+            if !self.map.contains_key(key) {
+                lines.push(format!("+ {:?}: {:?}", key, value));
+            }
+        }
+
+        write!(formatter, "{}", lines.join("\n"))
+    }
+}
+
 impl<K, V> FromIterator<(K, V)> for Map<K, V> {
     fn from_iter<I: IntoIterator<Item = (K, V)>>(iterator: I) -> Self {
         Self::new().insert_iter(iterator)
     }
 }
 
+pub enum Entry<K, V> {
+    Occupied(OccupiedEntry<K, V>),
+    Vacant(VacantEntry<K, V>),
+}
+
+impl<K: Eq, V> Entry<K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    pub fn or_insert(self, value: V) -> Map<K, V> {
+        self.or_insert_with(|| value)
+    }
+
+    pub fn or_insert_with(self, function: impl FnOnce() -> V) -> Map<K, V> {
+        match self {
+            Self::Occupied(entry) => entry.into_map(),
+            Self::Vacant(entry) => entry.insert(function()),
+        }
+    }
+
+    pub fn into_map(self) -> Map<K, V> {
+        match self {
+            Self::Occupied(entry) => entry.into_map(),
+            Self::Vacant(entry) => entry.into_map(),
+        }
+    }
+}
+
+impl<K: Clone + Eq, V> Entry<K, V> {
+    pub fn and_modify(self, function: impl FnOnce(&V) -> V) -> Self {
+        match self {
+            Self::Occupied(entry) => {
+                let value = function(entry.get());
+
+                Self::Occupied(OccupiedEntry {
+                    map: entry.map.insert(entry.key.clone(), value),
+                    key: entry.key,
+                })
+            }
+            Self::Vacant(entry) => Self::Vacant(entry),
+        }
+    }
+}
+
+pub struct OccupiedEntry<K, V> {
+    map: Map<K, V>,
+    key: K,
+}
+
+impl<K: Eq, V> OccupiedEntry<K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn get(&self) -> &V {
+        self.map.get(&self.key).expect("existent key")
+    }
+
+    pub fn insert(self, value: V) -> Map<K, V> {
+        self.map.insert(self.key, value)
+    }
+
+    pub fn into_map(self) -> Map<K, V> {
+        self.map
+    }
+}
+
+pub struct VacantEntry<K, V> {
+    map: Map<K, V>,
+    key: K,
+}
+
+impl<K, V> VacantEntry<K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn insert(self, value: V) -> Map<K, V> {
+        self.map.insert(self.key, value)
+    }
+
+    pub fn into_map(self) -> Map<K, V> {
+        self.map
+    }
+}
+
+impl<K, V> From<HashMap<K, V>> for Map<K, V> {
+    fn from(map: HashMap<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<K, V> From<BTreeMap<K, V>> for Map<K, V> {
+    fn from(map: BTreeMap<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> From<Map<K, V>> for HashMap<K, V> {
+    fn from(map: Map<K, V>) -> Self {
+        map.into_hash_map()
+    }
+}
+
+impl<K: Clone + Eq + Hash + Ord, V: Clone> From<Map<K, V>> for BTreeMap<K, V> {
+    fn from(map: Map<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
 pub struct MapIterator<'a, K: Eq + Hash, V> {
     iterator: list::ListIterator<'a, (K, V)>,
     set: HashSet<&'a K>,
+    buffer: Option<VecDeque<(&'a K, &'a V)>>,
 }
 
 impl<'a, K: Eq + Hash, V> IntoIterator for &'a Map<K, V> {
@@ -147,6 +696,7 @@ impl<'a, K: Eq + Hash, V> IntoIterator for &'a Map<K, V> {
         MapIterator {
             set: Default::default(),
             iterator: self.0.into_iter(),
+            buffer: None,
         }
     }
 }
@@ -155,6 +705,10 @@ impl<'a, K: Eq + Hash, V> Iterator for MapIterator<'a, K, V> {
     type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(buffer) = &mut self.buffer {
+            return buffer.pop_front();
+        }
+
         if let Some((key, value)) = self.iterator.next() {
             if self.set.contains(key) {
                 return self.next();
@@ -169,10 +723,59 @@ impl<'a, K: Eq + Hash, V> Iterator for MapIterator<'a, K, V> {
     }
 }
 
+impl<K: Eq + Hash, V> DoubleEndedIterator for MapIterator<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_none() {
+            self.buffer = Some(self.by_ref().collect());
+        }
+
+        self.buffer.as_mut()?.pop_back()
+    }
+}
+
+pub struct MapIntoIterator<K, V> {
+    iterator: list::ListIntoIterator<(K, V)>,
+    visible: vec::IntoIter<bool>,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> IntoIterator for Map<K, V> {
+    type Item = (K, V);
+    type IntoIter = MapIntoIterator<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut set = HashSet::new();
+        let visible = self
+            .0
+            .iter()
+            .map(|(key, _)| set.insert(key))
+            .collect::<Vec<_>>();
+
+        MapIntoIterator {
+            iterator: self.0.into_iter(),
+            visible: visible.into_iter(),
+        }
+    }
+}
+
+impl<K: Clone, V: Clone> Iterator for MapIntoIterator<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entry = self.iterator.next()?;
+
+            if self.visible.next()? {
+                return Some(entry);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::BTreeMap;
+
+    type Update = fn(Option<&usize>) -> Option<usize>;
 
     #[test]
     fn new() {
@@ -244,9 +847,7 @@ mod tests {
     #[test]
     fn into_iter() {
         assert_eq!(
-            Map::new()
-                .insert(1, 1)
-                .insert(2, 2)
+            (&Map::new().insert(1, 1).insert(2, 2))
                 .into_iter()
                 .collect::<HashSet<_>>(),
             [(&1, &1), (&2, &2)].into_iter().collect()
@@ -292,4 +893,606 @@ mod tests {
             format!("{:?}", BTreeMap::<_, _>::from_iter([(1, 2), (3, 4)]))
         );
     }
+
+    #[test]
+    fn iter() {
+        let map = Map::from_iter([(1, 1), (2, 2), (1, 3)]);
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            (&map).into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn into_iter_rev() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (1, 3), (3, 3)]);
+
+        assert_eq!(
+            map.iter().rev().collect::<Vec<_>>(),
+            map.iter()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .collect::<Vec<_>>()
+        );
+
+        let mut iterator = map.iter();
+
+        assert_eq!(iterator.next(), Some((&3, &3)));
+        assert_eq!(iterator.next_back(), Some((&2, &2)));
+        assert_eq!(iterator.next(), Some((&1, &3)));
+        assert_eq!(iterator.next_back(), None);
+    }
+
+    #[test]
+    fn remove_many() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (3, 3), (1, 4)]);
+
+        assert_eq!(map.remove_many([&1, &3]), Map::new().insert(2, 2));
+        assert_eq!(map.remove_many([&5]), map);
+        assert_eq!(map.remove_many([]), map);
+    }
+
+    #[test]
+    fn retain() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (3, 3), (1, 4)]);
+
+        assert_eq!(
+            map.retain(|_, value| value % 2 == 0),
+            Map::new().insert_iter([(2, 2), (1, 4)])
+        );
+        assert_eq!(map.retain(|key, _| *key == 1).get(&1), Some(&4));
+        assert_eq!(map.retain(|_, _| true).0.len(), 3);
+    }
+
+    #[test]
+    fn entry() {
+        let map = Map::new().insert(1, 1);
+
+        assert_eq!(map.entry(1).key(), &1);
+        assert!(matches!(map.entry(1), Entry::Occupied(_)));
+        assert!(matches!(map.entry(2), Entry::Vacant(_)));
+        assert_eq!(map.entry(1).into_map(), map);
+    }
+
+    #[test]
+    fn entry_or_insert() {
+        let map = Map::new().insert(1, 1);
+
+        assert_eq!(map.entry(1).or_insert(2), map);
+        assert_eq!(map.entry(2).or_insert(2), map.insert(2, 2));
+        assert_eq!(map.entry(2).or_insert_with(|| 3), map.insert(2, 3));
+    }
+
+    #[test]
+    fn entry_and_modify() {
+        let map = Map::new().insert(1, 1);
+
+        assert_eq!(
+            map.entry(1).and_modify(|value| value + 1).or_insert(0),
+            Map::new().insert(1, 2)
+        );
+        assert_eq!(
+            map.entry(2).and_modify(|value| value + 1).or_insert(0),
+            map.insert(2, 0)
+        );
+    }
+
+    #[test]
+    fn occupied_entry() {
+        let map = Map::new().insert(1, 1);
+
+        if let Entry::Occupied(entry) = map.entry(1) {
+            assert_eq!(entry.get(), &1);
+            assert_eq!(entry.insert(2), Map::new().insert(1, 2));
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn union() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2)]);
+        let other = Map::new().insert_iter([(2, 20), (3, 30)]);
+
+        assert_eq!(
+            map.union(&other, |_, value, other| value + other),
+            Map::new().insert_iter([(1, 1), (2, 22), (3, 30)])
+        );
+        assert_eq!(map.union(&Map::new(), |_, value, _| *value), map);
+        assert_eq!(Map::new().union(&other, |_, value, _| *value), other);
+    }
+
+    #[test]
+    fn left_union() {
+        assert_eq!(
+            Map::new()
+                .insert_iter([(1, 1), (2, 2)])
+                .left_union(&Map::new().insert_iter([(2, 20), (3, 30)])),
+            Map::new().insert_iter([(1, 1), (2, 2), (3, 30)])
+        );
+    }
+
+    #[test]
+    fn right_union() {
+        assert_eq!(
+            Map::new()
+                .insert_iter([(1, 1), (2, 2)])
+                .right_union(&Map::new().insert_iter([(2, 20), (3, 30)])),
+            Map::new().insert_iter([(1, 1), (2, 20), (3, 30)])
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (3, 3)]);
+        let other = Map::new().insert_iter([(2, 20), (3, 30), (4, 40)]);
+
+        assert_eq!(
+            map.intersection(&other, |_, value, other| value + other),
+            Map::new().insert_iter([(2, 22), (3, 33)])
+        );
+        assert!(map
+            .intersection(&Map::new(), |_, value, _| *value)
+            .is_empty());
+    }
+
+    #[test]
+    fn difference() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (3, 3)]);
+
+        assert_eq!(
+            map.difference(&Map::new().insert_iter([(2, 20), (4, 40)])),
+            Map::new().insert_iter([(1, 1), (3, 3)])
+        );
+        assert_eq!(map.difference(&Map::new()), map);
+        assert!(map.difference(&map).is_empty());
+    }
+
+    #[test]
+    fn filter() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (1, 3)]);
+        let filtered = map.filter(|_, value| value % 2 == 1);
+
+        assert_eq!(filtered, Map::new().insert(1, 3));
+        assert_eq!(filtered.0.len(), 1);
+    }
+
+    #[test]
+    fn pop() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (1, 3)]);
+        let ((key, value), rest) = map.pop().unwrap();
+
+        assert_eq!((key, value), (&1, &3));
+        assert_eq!(rest, Map::new().insert_iter([(1, 1), (2, 2)]));
+        assert_eq!(rest.pop().unwrap().0, (&2, &2));
+        assert!(Map::<(), ()>::new().pop().is_none());
+    }
+
+    #[test]
+    fn is_submap_of() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2)]);
+
+        assert!(Map::new().is_submap_of(&map));
+        assert!(map.is_submap_of(&map));
+        assert!(Map::new().insert(1, 1).is_submap_of(&map));
+        assert!(!Map::new().insert(1, 2).is_submap_of(&map));
+        assert!(!Map::new().insert(3, 3).is_submap_of(&map));
+        assert!(!map.insert(2, 3).is_submap_of(&map.insert(1, 1)));
+    }
+
+    #[test]
+    fn is_supermap_of() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2)]);
+
+        assert!(map.is_supermap_of(&Map::new()));
+        assert!(map.is_supermap_of(&Map::new().insert(2, 2)));
+        assert!(!map.is_supermap_of(&Map::new().insert(2, 3)));
+    }
+
+    #[test]
+    fn is_disjoint() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2)]);
+
+        assert!(map.is_disjoint(&Map::new()));
+        assert!(map.is_disjoint(&Map::new().insert(3, 1)));
+        assert!(!map.is_disjoint(&Map::new().insert(2, 3)));
+    }
+
+    #[test]
+    fn merge_many() {
+        assert!(Map::<(), ()>::merge_many([]).is_empty());
+        assert_eq!(
+            Map::merge_many([
+                Map::new().insert_iter([(1, 1), (2, 1)]),
+                Map::new().insert_iter([(2, 2), (3, 2)]),
+                Map::new().insert(3, 3),
+            ]),
+            Map::new().insert_iter([(1, 1), (2, 2), (3, 3)])
+        );
+    }
+
+    #[test]
+    fn into_iter_owned() {
+        assert_eq!(
+            Map::new()
+                .insert_iter([(1, 1), (2, 2), (1, 3)])
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![(1, 3), (2, 2)]
+        );
+        assert_eq!(
+            Map::<usize, usize>::new().into_iter().collect::<Vec<_>>(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn into_iter_owned_shared() {
+        let map = Map::new().insert_iter([(1, "foo".to_string()), (2, "bar".into())]);
+        let other = map.insert(1, "baz".into());
+
+        assert_eq!(
+            other.into_iter().collect::<Vec<_>>(),
+            vec![(1, "baz".into()), (2, "bar".into())]
+        );
+        assert_eq!(map.get(&1), Some(&"foo".into()));
+    }
+
+    #[test]
+    fn from_hash_map() {
+        assert_eq!(
+            Map::from(HashMap::from([(1, 1), (2, 2)])),
+            Map::new().insert_iter([(1, 1), (2, 2)])
+        );
+    }
+
+    #[test]
+    fn from_btree_map() {
+        assert_eq!(
+            Map::from(BTreeMap::from([(1, 1), (2, 2)])),
+            Map::new().insert_iter([(1, 1), (2, 2)])
+        );
+    }
+
+    #[test]
+    fn into_hash_map() {
+        assert_eq!(
+            HashMap::from(Map::new().insert_iter([(1, 1), (2, 2), (1, 3)])),
+            HashMap::from([(1, 3), (2, 2)])
+        );
+    }
+
+    #[test]
+    fn into_btree_map() {
+        let map: BTreeMap<_, _> = Map::new().insert_iter([(1, 1), (2, 2), (1, 3)]).into();
+
+        assert_eq!(map, BTreeMap::from([(1, 3), (2, 2)]));
+    }
+
+    #[test]
+    fn iter_sorted() {
+        assert_eq!(Map::<usize, usize>::new().iter_sorted().count(), 0);
+        assert_eq!(
+            Map::new()
+                .insert_iter([(3, 3), (1, 1), (2, 2), (1, 4)])
+                .iter_sorted()
+                .collect::<Vec<_>>(),
+            vec![(&1, &4), (&2, &2), (&3, &3)]
+        );
+    }
+
+    #[test]
+    fn get_or_insert_with() {
+        let map = Map::new().insert(1, 1);
+
+        assert_eq!(
+            map.get_or_insert_with(1, || unreachable!()),
+            (1, map.clone())
+        );
+        assert_eq!(map.get_or_insert_with(2, || 2), (2, map.insert(2, 2)));
+    }
+
+    #[test]
+    fn try_insert() {
+        let map = Map::new().insert(1, 1);
+
+        assert_eq!(map.try_insert(2, 2), Ok(map.insert(2, 2)));
+        assert_eq!(
+            map.try_insert(1, 2),
+            Err(OccupiedError { key: 1, value: 2 })
+        );
+        assert_eq!(map.try_insert(1, 2).unwrap_err().key(), &1);
+        assert_eq!(map.try_insert(1, 2).unwrap_err().value(), &2);
+        assert_eq!(
+            map.try_insert(1, 2).unwrap_err().to_string(),
+            "key 1 already exists"
+        );
+    }
+
+    #[test]
+    fn contains_entry() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (1, 3)]);
+
+        assert!(map.contains_entry(&1, &3));
+        assert!(map.contains_entry(&2, &2));
+        assert!(!map.contains_entry(&1, &1));
+        assert!(!map.contains_entry(&3, &3));
+    }
+
+    #[test]
+    fn history() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (1, 3), (1, 4)]);
+
+        assert_eq!(map.history(&1).collect::<Vec<_>>(), vec![&4, &3, &1]);
+        assert_eq!(map.history(&2).collect::<Vec<_>>(), vec![&2]);
+        assert_eq!(map.history(&3).count(), 0);
+    }
+
+    #[test]
+    fn raw_len() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (1, 3)]);
+
+        assert_eq!(Map::<(), ()>::new().raw_len(), 0);
+        assert_eq!(map.raw_len(), 3);
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn singleton() {
+        assert_eq!(Map::singleton(1, 2), Map::new().insert(1, 2));
+        assert_eq!(Map::singleton(1, 2).len(), 1);
+    }
+
+    #[test]
+    fn retain_keys() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (3, 3), (1, 4)]);
+
+        assert_eq!(
+            map.retain_keys(&HashSet::from([1, 3, 5])),
+            Map::new().insert_iter([(3, 3), (1, 4)])
+        );
+        assert!(map.retain_keys(&HashSet::new()).is_empty());
+    }
+
+    #[test]
+    fn without_keys() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (3, 3), (1, 4)]);
+
+        assert_eq!(
+            map.without_keys(&HashSet::from([1, 3, 5])),
+            Map::new().insert(2, 2)
+        );
+        assert_eq!(map.without_keys(&HashSet::new()), map);
+    }
+
+    #[test]
+    fn join() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2)]);
+        let other = Map::new().insert_iter([(2, "two"), (3, "three")]);
+
+        assert_eq!(map.join(&other), Map::new().insert(2, (2, "two")));
+        assert!(map.join(&Map::<usize, ()>::new()).is_empty());
+    }
+
+    #[test]
+    fn left_join() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2)]);
+        let other = Map::new().insert_iter([(2, "two"), (3, "three")]);
+
+        assert_eq!(
+            map.left_join(&other),
+            Map::new().insert_iter([(1, (1, None)), (2, (2, Some("two")))])
+        );
+    }
+
+    #[test]
+    fn get_many() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (1, 3)]);
+
+        assert_eq!(map.get_many::<usize, 0>([]), []);
+        assert_eq!(
+            map.get_many([&1, &2, &3, &1]),
+            [Some(&3), Some(&2), None, Some(&3)]
+        );
+    }
+
+    #[test]
+    fn update_many() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (3, 3)]);
+        let updates: [(usize, Update); 4] = [
+            (1, |value| value.map(|value| value + 10)),
+            (2, |_| None),
+            (4, |value| Some(value.copied().unwrap_or(40))),
+            (5, |_| None),
+        ];
+
+        assert_eq!(
+            map.update_many(updates),
+            Map::new().insert_iter([(1, 11), (3, 3), (4, 40)])
+        );
+    }
+
+    #[test]
+    fn update_many_order() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (3, 3)]);
+        let updates: [(usize, Update); 3] = [
+            (4, |_| Some(4)),
+            (3, |_| None),
+            (1, |value| value.map(|value| value + 10)),
+        ];
+        let other = map.update_many(updates);
+
+        assert_eq!(
+            other.0.iter().collect::<Vec<_>>(),
+            vec![&(1, 11), &(4, 4), &(2, 2), &(1, 1)]
+        );
+        assert!(other.0.suffix_at(2).ptr_eq(&map.0.suffix_at(1)));
+    }
+
+    #[test]
+    fn update_many_same_key() {
+        let map = Map::new().insert(1, 1);
+        let updates: [(usize, Update); 3] = [
+            (1, |value| value.map(|value| value + 1)),
+            (1, |_| None),
+            (1, |value| Some(value.copied().unwrap_or(42))),
+        ];
+
+        assert_eq!(map.update_many(updates), Map::new().insert(1, 42));
+        assert_eq!(
+            map.update_many(updates[..1].to_vec()),
+            Map::new().insert(1, 2)
+        );
+    }
+
+    #[test]
+    fn rename_key() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (1, 3)]);
+
+        assert_eq!(
+            map.rename_key(&1, 4),
+            Some(Map::new().insert_iter([(2, 2), (4, 3)]))
+        );
+        assert_eq!(map.rename_key(&1, 2), Some(Map::new().insert(2, 3)));
+        assert_eq!(map.rename_key(&1, 1), Some(map.clone()));
+        assert_eq!(map.rename_key(&5, 6), None);
+    }
+
+    #[test]
+    fn rename_key_shares_suffix() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (3, 3), (4, 4)]);
+        let other = map.rename_key(&3, 5).unwrap();
+
+        assert_eq!(
+            other.0.iter().collect::<Vec<_>>(),
+            vec![&(5, 3), &(4, 4), &(2, 2), &(1, 1)]
+        );
+        assert!(other.0.suffix_at(2).ptr_eq(&map.0.suffix_at(2)));
+    }
+
+    #[test]
+    fn to_vec() {
+        assert_eq!(Map::<(), ()>::new().to_vec(), vec![]);
+        assert_eq!(
+            Map::new().insert_iter([(1, 1), (3, 3), (1, 2)]).to_vec(),
+            vec![(1, 2), (3, 3)]
+        );
+    }
+
+    #[test]
+    fn to_sorted_vec() {
+        assert_eq!(
+            Map::new()
+                .insert_iter([(3, 3), (1, 1), (2, 2), (3, 4)])
+                .to_sorted_vec(),
+            vec![(1, 1), (2, 2), (3, 4)]
+        );
+    }
+
+    #[test]
+    fn partition() {
+        let (even, odd) = Map::new()
+            .insert_iter([(1, 1), (2, 2), (3, 3), (1, 4)])
+            .partition(|_, value| value % 2 == 0);
+
+        assert_eq!(even, Map::new().insert_iter([(2, 2), (1, 4)]));
+        assert_eq!(odd, Map::new().insert(3, 3));
+    }
+
+    #[test]
+    fn shadowed() {
+        assert_eq!(
+            Map::new().insert_iter([(1, 1), (2, 2)]).shadowed().count(),
+            0
+        );
+        assert_eq!(
+            Map::new()
+                .insert_iter([(1, 1), (2, 2), (1, 3), (1, 4)])
+                .shadowed()
+                .collect::<Vec<_>>(),
+            vec![(&1, &3), (&1, &1)]
+        );
+    }
+
+    #[test]
+    fn iter_range() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (3, 3), (4, 4), (2, 5)]);
+
+        assert_eq!(
+            map.iter_range(2..4).collect::<Vec<_>>(),
+            vec![(&2, &5), (&3, &3)]
+        );
+        assert_eq!(map.iter_range(..=1).collect::<Vec<_>>(), vec![(&1, &1)]);
+        assert_eq!(map.iter_range(..).count(), 4);
+        assert_eq!(map.iter_range(5..).count(), 0);
+    }
+
+    #[test]
+    fn iter_prefix() {
+        let map = Map::new().insert_iter([
+            ("foo".to_string(), 1),
+            ("foobar".into(), 2),
+            ("bar".into(), 3),
+            ("foo".into(), 4),
+        ]);
+
+        assert_eq!(
+            map.iter_prefix("foo").collect::<Vec<_>>(),
+            vec![(&"foo".into(), &4), (&"foobar".into(), &2)]
+        );
+        assert_eq!(map.iter_prefix("").count(), 3);
+        assert_eq!(map.iter_prefix("baz").count(), 0);
+    }
+
+    #[test]
+    fn replace() {
+        let map = Map::new().insert(1, 1);
+
+        assert_eq!(map.replace(1, 2), (Some(&1), Map::new().insert(1, 2)));
+        assert_eq!(map.replace(2, 2), (None, map.insert(2, 2)));
+    }
+
+    #[test]
+    fn into_hash_map_unique() {
+        #[derive(Debug, PartialEq)]
+        struct Value(usize);
+
+        impl Clone for Value {
+            fn clone(&self) -> Self {
+                unreachable!()
+            }
+        }
+
+        assert_eq!(
+            Map::new()
+                .insert_iter([(1, Value(1)), (2, Value(2)), (1, Value(3))])
+                .into_hash_map(),
+            HashMap::from([(1, Value(3)), (2, Value(2))])
+        );
+    }
+
+    #[test]
+    fn into_hash_map_shared() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2)]);
+
+        assert_eq!(
+            map.insert(1, 3).into_hash_map(),
+            HashMap::from([(1, 3), (2, 2)])
+        );
+        assert_eq!(map.into_hash_map(), HashMap::from([(1, 1), (2, 2)]));
+    }
+
+    #[test]
+    fn diff_display() {
+        let map = Map::new().insert_iter([(1, 1), (2, 2), (3, 3)]);
+
+        assert_eq!(map.diff_display(&map).to_string(), "");
+        assert_eq!(
+            map.diff_display(&Map::new().insert_iter([(1, 1), (3, 4), (4, 4)]))
+                .to_string(),
+            "~ 3: 3 -> 4\n- 2: 2\n+ 4: 4"
+        );
+    }
 }
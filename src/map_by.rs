@@ -0,0 +1,295 @@
+use crate::{list, List};
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+    fmt::{self, Debug, Formatter},
+    hash::Hash,
+    marker::PhantomData,
+    ops::Index,
+};
+
+pub trait KeyComparator<K: ?Sized> {
+    type Key: Eq + Hash;
+
+    fn normalize(key: &K) -> Self::Key;
+}
+
+pub struct CaseInsensitive;
+
+impl<K: AsRef<str> + ?Sized> KeyComparator<K> for CaseInsensitive {
+    type Key = String;
+
+    fn normalize(key: &K) -> Self::Key {
+        key.as_ref().to_lowercase()
+    }
+}
+
+pub struct MapBy<K, V, C> {
+    entries: List<(K, V)>,
+    comparator: PhantomData<C>,
+}
+
+impl<K, V, C> MapBy<K, V, C> {
+    pub fn new() -> Self {
+        Self {
+            entries: Default::default(),
+            comparator: PhantomData,
+        }
+    }
+
+    pub fn insert(&self, key: K, value: V) -> Self {
+        Self {
+            entries: self.entries.push_front((key, value)),
+            comparator: PhantomData,
+        }
+    }
+
+    pub fn insert_iter(&self, iterator: impl IntoIterator<Item = (K, V)>) -> Self {
+        Self {
+            entries: self.entries.push_front_iter(iterator),
+            comparator: PhantomData,
+        }
+    }
+
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        C: KeyComparator<Q>,
+    {
+        let key = C::normalize(key);
+
+        self.entries.iter().find_map(|(other_key, value)| {
+            if C::normalize(other_key.borrow()) == key {
+                Some(value)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        C: KeyComparator<Q>,
+    {
+        self.get(key).is_some()
+    }
+}
+
+impl<K, V, C: KeyComparator<K>> MapBy<K, V, C> {
+    pub fn len(&self) -> usize {
+        self.into_iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> MapByIterator<'_, K, V, C> {
+        self.into_iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.into_iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.into_iter().map(|(_, value)| value)
+    }
+}
+
+impl<Q: ?Sized, K, V, C: KeyComparator<Q>> Index<&Q> for MapBy<K, V, C>
+where
+    K: Borrow<Q>,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("existent key")
+    }
+}
+
+impl<K, V, C> Clone for MapBy<K, V, C> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            comparator: PhantomData,
+        }
+    }
+}
+
+impl<K, V, C> Default for MapBy<K, V, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug, C: KeyComparator<K>> Debug for MapBy<K, V, C> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{{")?;
+
+        for (index, (key, value)) in self.into_iter().enumerate() {
+            write!(formatter, "{:?}: {:?}", key, value)?;
+
+            if index < self.len() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "}}")?;
+
+        Ok(())
+    }
+}
+
+impl<K, V: PartialEq, C: KeyComparator<K>> PartialEq for MapBy<K, V, C> {
+    fn eq(&self, other: &Self) -> bool {
+        let entries = self
+            .into_iter()
+            .map(|(key, value)| (C::normalize(key), value))
+            .collect::<HashMap<_, _>>();
+
+        entries.len() == other.len()
+            && other
+                .into_iter()
+                .all(|(key, value)| entries.get(&C::normalize(key)) == Some(&value))
+    }
+}
+
+impl<K, V: Eq, C: KeyComparator<K>> Eq for MapBy<K, V, C> {}
+
+impl<K, V, C> FromIterator<(K, V)> for MapBy<K, V, C> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iterator: I) -> Self {
+        Self::new().insert_iter(iterator)
+    }
+}
+
+pub struct MapByIterator<'a, K, V, C: KeyComparator<K>> {
+    iterator: list::ListIterator<'a, (K, V)>,
+    set: HashSet<C::Key>,
+}
+
+impl<'a, K, V, C: KeyComparator<K>> IntoIterator for &'a MapBy<K, V, C> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = MapByIterator<'a, K, V, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MapByIterator {
+            iterator: self.entries.iter(),
+            set: Default::default(),
+        }
+    }
+}
+
+impl<'a, K, V, C: KeyComparator<K>> Iterator for MapByIterator<'a, K, V, C> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (key, value) = self.iterator.next()?;
+
+            if self.set.insert(C::normalize(key)) {
+                return Some((key, value));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CaseInsensitiveMap<V> = MapBy<String, V, CaseInsensitive>;
+
+    #[test]
+    fn new() {
+        CaseInsensitiveMap::<()>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(CaseInsensitiveMap::<()>::new(), CaseInsensitiveMap::new());
+        assert_eq!(
+            CaseInsensitiveMap::new().insert("foo".into(), 1),
+            CaseInsensitiveMap::new().insert("FOO".into(), 1)
+        );
+        assert_ne!(
+            CaseInsensitiveMap::new().insert("foo".into(), 1),
+            CaseInsensitiveMap::new().insert("foo".into(), 2)
+        );
+        assert_ne!(
+            CaseInsensitiveMap::new(),
+            CaseInsensitiveMap::new().insert("foo".into(), 1)
+        );
+        assert_ne!(
+            CaseInsensitiveMap::new().insert("foo".into(), 1),
+            CaseInsensitiveMap::new()
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(CaseInsensitiveMap::<()>::new().len(), 0);
+        assert_eq!(
+            CaseInsensitiveMap::new()
+                .insert("foo".into(), 1)
+                .insert("Foo".into(), 2)
+                .insert("bar".into(), 3)
+                .len(),
+            2
+        );
+        assert!(CaseInsensitiveMap::<()>::new().is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let map = CaseInsensitiveMap::new()
+            .insert("foo".into(), 1)
+            .insert("Bar".into(), 2)
+            .insert("FOO".into(), 3);
+
+        assert_eq!(map.get("foo"), Some(&3));
+        assert_eq!(map.get("BAR"), Some(&2));
+        assert_eq!(map.get("baz"), None);
+        assert_eq!(map["bar"], 2);
+        assert!(map.contains_key("Foo"));
+        assert!(!map.contains_key("baz"));
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            CaseInsensitiveMap::new()
+                .insert("foo".into(), 1)
+                .insert("bar".into(), 2)
+                .insert("FOO".into(), 3)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![(&"FOO".into(), &3), (&"bar".into(), &2)]
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", CaseInsensitiveMap::<()>::new()), "{}");
+        assert_eq!(
+            format!(
+                "{:?}",
+                CaseInsensitiveMap::new()
+                    .insert("foo".into(), 1)
+                    .insert("Foo".into(), 2)
+            ),
+            "{\"Foo\": 2}"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let map = CaseInsensitiveMap::from_iter([("foo".into(), 1), ("bar".into(), 2)]);
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            (&map).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
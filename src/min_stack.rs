@@ -0,0 +1,223 @@
+use crate::{list, List};
+use std::{
+    fmt::{self, Debug, Formatter},
+    rc::Rc,
+};
+
+pub struct MinStack<T>(List<Entry<T>>);
+
+struct Entry<T> {
+    value: Rc<T>,
+    min: Rc<T>,
+}
+
+impl<T> MinStack<T> {
+    pub fn new() -> Self {
+        Self(Default::default())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.0.into_iter().next().map(|entry| entry.value.as_ref())
+    }
+
+    pub fn min(&self) -> Option<&T> {
+        self.0.into_iter().next().map(|entry| entry.min.as_ref())
+    }
+
+    pub fn pop(&self) -> Self {
+        Self(self.0.pop_front())
+    }
+
+    pub fn iter(&self) -> MinStackIterator<'_, T> {
+        self.into_iter()
+    }
+}
+
+impl<T: Ord> MinStack<T> {
+    pub fn push(&self, value: T) -> Self {
+        let value = Rc::new(value);
+        let min = match self.0.into_iter().next() {
+            Some(entry) if entry.min <= value => entry.min.clone(),
+            _ => value.clone(),
+        };
+
+        Self(self.0.push_front(Entry { value, min }))
+    }
+
+    pub fn push_iter(&self, iterator: impl IntoIterator<Item = T>) -> Self {
+        let mut stack = self.clone();
+
+        for value in iterator {
+            stack = stack.push(value);
+        }
+
+        stack
+    }
+}
+
+impl<T> Clone for MinStack<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Default for MinStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for MinStack<T> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "[")?;
+
+        for (index, value) in self.into_iter().enumerate() {
+            write!(formatter, "{:?}", value)?;
+
+            if index < self.len() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "]")?;
+
+        Ok(())
+    }
+}
+
+impl<T: PartialEq> PartialEq for MinStack<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.into_iter().eq(other)
+    }
+}
+
+impl<T: Eq> Eq for MinStack<T> {}
+
+impl<T: Ord> FromIterator<T> for MinStack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        Self::new().push_iter(iterator)
+    }
+}
+
+pub struct MinStackIterator<'a, T>(list::ListIterator<'a, Entry<T>>);
+
+impl<'a, T> IntoIterator for &'a MinStack<T> {
+    type Item = &'a T;
+    type IntoIter = MinStackIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        MinStackIterator(self.0.into_iter())
+    }
+}
+
+impl<'a, T> Iterator for MinStackIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|entry| entry.value.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        MinStack::<()>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(MinStack::<()>::new(), MinStack::new());
+        assert_ne!(MinStack::new(), MinStack::new().push(1));
+        assert_eq!(MinStack::new().push(1), MinStack::new().push(1));
+        assert_ne!(
+            MinStack::new().push(1).push(2),
+            MinStack::new().push(2).push(1)
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(MinStack::<()>::new().len(), 0);
+        assert_eq!(MinStack::new().push(1).len(), 1);
+        assert_eq!(MinStack::new().push(1).push(2).len(), 2);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(MinStack::<()>::new().is_empty());
+        assert!(!MinStack::new().push(1).is_empty());
+    }
+
+    #[test]
+    fn peek() {
+        assert_eq!(MinStack::<()>::new().peek(), None);
+        assert_eq!(MinStack::new().push(1).push(2).peek(), Some(&2));
+    }
+
+    #[test]
+    fn pop() {
+        assert_eq!(MinStack::<()>::new().pop(), MinStack::new());
+        assert_eq!(
+            MinStack::new().push(1).push(2).pop(),
+            MinStack::new().push(1)
+        );
+    }
+
+    #[test]
+    fn min() {
+        let stack = MinStack::from_iter([3, 1, 2]);
+
+        assert_eq!(MinStack::<()>::new().min(), None);
+        assert_eq!(stack.min(), Some(&1));
+        assert_eq!(stack.pop().min(), Some(&1));
+        assert_eq!(stack.pop().pop().min(), Some(&3));
+        assert_eq!(stack.push(0).min(), Some(&0));
+        assert_eq!(stack.min(), Some(&1));
+    }
+
+    #[test]
+    fn min_duplicates() {
+        let stack = MinStack::from_iter([1, 1]);
+
+        assert_eq!(stack.min(), Some(&1));
+        assert_eq!(stack.pop().min(), Some(&1));
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            MinStack::from_iter([1, 2, 3])
+                .into_iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", MinStack::<()>::new()), "[]");
+        assert_eq!(format!("{:?}", MinStack::from_iter([1, 2])), "[2, 1]");
+    }
+
+    #[test]
+    fn iter() {
+        let stack = MinStack::from_iter([1, 2, 3]);
+
+        assert_eq!(
+            stack.iter().collect::<Vec<_>>(),
+            (&stack).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
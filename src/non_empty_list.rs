@@ -0,0 +1,191 @@
+use crate::{list, List};
+use std::fmt::{self, Debug, Formatter};
+
+#[derive(Eq, PartialEq, PartialOrd, Ord)]
+pub struct NonEmptyList<T>(List<T>);
+
+impl<T> NonEmptyList<T> {
+    pub fn new(head: T) -> Self {
+        Self(List::new().push_front(head))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn first(&self) -> &T {
+        self.0.first().expect("non-empty list")
+    }
+
+    pub fn last(&self) -> &T {
+        self.0.last().expect("non-empty list")
+    }
+
+    pub fn tail(&self) -> List<T> {
+        self.0.pop_front()
+    }
+
+    pub fn push_front(&self, head: T) -> Self {
+        Self(self.0.push_front(head))
+    }
+
+    pub fn push_front_iter(&self, iterator: impl IntoIterator<Item = T>) -> Self {
+        Self(self.0.push_front_iter(iterator))
+    }
+
+    pub fn as_list(&self) -> &List<T> {
+        &self.0
+    }
+
+    pub fn iter(&self) -> list::ListIterator<'_, T> {
+        self.into_iter()
+    }
+}
+
+impl<T> Clone for NonEmptyList<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Debug> Debug for NonEmptyList<T> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self.0)
+    }
+}
+
+impl<T> From<NonEmptyList<T>> for List<T> {
+    fn from(list: NonEmptyList<T>) -> Self {
+        list.0
+    }
+}
+
+impl<T> TryFrom<List<T>> for NonEmptyList<T> {
+    type Error = List<T>;
+
+    fn try_from(list: List<T>) -> Result<Self, Self::Error> {
+        if list.is_empty() {
+            Err(list)
+        } else {
+            Ok(Self(list))
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyList<T> {
+    type Item = &'a T;
+    type IntoIter = list::ListIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        NonEmptyList::new(42);
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(NonEmptyList::new(1), NonEmptyList::new(1));
+        assert_ne!(NonEmptyList::new(1), NonEmptyList::new(2));
+        assert_ne!(NonEmptyList::new(1), NonEmptyList::new(1).push_front(1));
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(NonEmptyList::new(1).len(), 1);
+        assert_eq!(NonEmptyList::new(1).push_front(2).len(), 2);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!NonEmptyList::new(1).is_empty());
+    }
+
+    #[test]
+    fn first() {
+        assert_eq!(NonEmptyList::new(1).first(), &1);
+        assert_eq!(NonEmptyList::new(1).push_front(2).first(), &2);
+    }
+
+    #[test]
+    fn last() {
+        assert_eq!(NonEmptyList::new(1).last(), &1);
+        assert_eq!(NonEmptyList::new(1).push_front(2).last(), &1);
+    }
+
+    #[test]
+    fn tail() {
+        assert_eq!(NonEmptyList::new(1).tail(), List::new());
+        assert_eq!(
+            NonEmptyList::new(1).push_front(2).tail(),
+            List::new().push_front(1)
+        );
+    }
+
+    #[test]
+    fn push_front_iter() {
+        assert_eq!(
+            NonEmptyList::new(1).push_front(2).push_front(3),
+            NonEmptyList::new(1).push_front_iter([2, 3]),
+        );
+    }
+
+    #[test]
+    fn from_list() {
+        assert_eq!(
+            NonEmptyList::try_from(List::from_iter([1, 2])),
+            Ok(NonEmptyList::new(1).push_front(2))
+        );
+        assert_eq!(NonEmptyList::try_from(List::<()>::new()), Err(List::new()));
+    }
+
+    #[test]
+    fn into_list() {
+        assert_eq!(
+            List::from(NonEmptyList::new(1).push_front(2)),
+            List::from_iter([1, 2])
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            NonEmptyList::new(1)
+                .push_front(2)
+                .into_iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", NonEmptyList::new(1)), "[1]");
+        assert_eq!(
+            format!("{:?}", NonEmptyList::new(1).push_front(2)),
+            "[2, 1]"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let list = NonEmptyList::new(1).push_front(2);
+
+        assert_eq!(
+            list.iter().collect::<Vec<_>>(),
+            (&list).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,353 @@
+use crate::{list, seq, List, Seq};
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    ops::{Bound, RangeBounds},
+};
+
+pub struct OrdMultiMap<K, V> {
+    entries: Seq<(K, List<V>)>,
+    len: usize,
+}
+
+impl<K, V> OrdMultiMap<K, V> {
+    pub fn new() -> Self {
+        Self {
+            entries: Default::default(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> OrdMultiMapIterator<'_, K, V> {
+        self.into_iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.into_iter().map(|(key, _)| key)
+    }
+}
+
+impl<K: Ord, V> OrdMultiMap<K, V> {
+    pub fn get<Q: Ord + ?Sized>(&self, key: &Q) -> impl Iterator<Item = &V>
+    where
+        K: Borrow<Q>,
+    {
+        self.search(key)
+            .ok()
+            .and_then(|index| self.entries.get(index))
+            .into_iter()
+            .flat_map(|(_, values)| values)
+    }
+
+    pub fn contains_key<Q: Ord + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.search(key).is_ok()
+    }
+
+    pub fn range<Q: Ord + ?Sized>(
+        &self,
+        range: impl RangeBounds<Q>,
+    ) -> impl Iterator<Item = (&K, &V)>
+    where
+        K: Borrow<Q>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(key) => self.search(key).unwrap_or_else(|index| index),
+            Bound::Excluded(key) => self
+                .search(key)
+                .map_or_else(|index| index, |index| index + 1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(key) => self
+                .search(key)
+                .map_or_else(|index| index, |index| index + 1),
+            Bound::Excluded(key) => self.search(key).unwrap_or_else(|index| index),
+            Bound::Unbounded => self.entries.len(),
+        };
+
+        (start..end.max(start)).flat_map(|index| {
+            let (key, values) = &self.entries[index];
+
+            values.into_iter().map(move |value| (key, value))
+        })
+    }
+
+    fn search<Q: Ord + ?Sized>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+    {
+        let mut low = 0;
+        let mut high = self.entries.len();
+
+        while low < high {
+            let middle = (low + high) / 2;
+
+            match self.entries[middle].0.borrow().cmp(key) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => return Ok(middle),
+            }
+        }
+
+        Err(low)
+    }
+}
+
+impl<K: Clone + Ord, V> OrdMultiMap<K, V> {
+    pub fn insert(&self, key: K, value: V) -> Self {
+        Self {
+            entries: match self.search(&key) {
+                Ok(index) => self
+                    .entries
+                    .set(index, (key, self.entries[index].1.push_front(value))),
+                Err(index) => self
+                    .entries
+                    .insert(index, (key, List::new().push_front(value))),
+            },
+            len: self.len + 1,
+        }
+    }
+
+    pub fn insert_iter(&self, iterator: impl IntoIterator<Item = (K, V)>) -> Self {
+        let mut map = self.clone();
+
+        for (key, value) in iterator {
+            map = map.insert(key, value);
+        }
+
+        map
+    }
+
+    pub fn remove<Q: Ord + ?Sized>(&self, key: &Q) -> Self
+    where
+        K: Borrow<Q>,
+    {
+        if let Ok(index) = self.search(key) {
+            Self {
+                entries: self.entries.remove(index),
+                len: self.len - self.entries[index].1.len(),
+            }
+        } else {
+            self.clone()
+        }
+    }
+}
+
+impl<K, V> Clone for OrdMultiMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<K, V> Default for OrdMultiMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for OrdMultiMap<K, V> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{{")?;
+
+        for (index, (key, values)) in self.entries.into_iter().enumerate() {
+            write!(formatter, "{:?}: {:?}", key, values)?;
+
+            if index < self.entries.len() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "}}")?;
+
+        Ok(())
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for OrdMultiMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.entries == other.entries
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for OrdMultiMap<K, V> {}
+
+impl<K: Clone + Ord, V> FromIterator<(K, V)> for OrdMultiMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iterator: I) -> Self {
+        Self::new().insert_iter(iterator)
+    }
+}
+
+pub struct OrdMultiMapIterator<'a, K, V> {
+    entries: seq::SeqIterator<'a, (K, List<V>)>,
+    current: Option<(&'a K, list::ListIterator<'a, V>)>,
+}
+
+impl<'a, K, V> IntoIterator for &'a OrdMultiMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = OrdMultiMapIterator<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        OrdMultiMapIterator {
+            entries: self.entries.into_iter(),
+            current: None,
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for OrdMultiMapIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, values)) = &mut self.current {
+                if let Some(value) = values.next() {
+                    return Some((key, value));
+                }
+            }
+
+            let (key, values) = self.entries.next()?;
+            self.current = Some((key, values.into_iter()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        OrdMultiMap::<(), ()>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(OrdMultiMap::<(), ()>::new(), OrdMultiMap::new());
+        assert_ne!(OrdMultiMap::new(), OrdMultiMap::new().insert(1, 1));
+        assert_eq!(
+            OrdMultiMap::new().insert(1, 1).insert(2, 2),
+            OrdMultiMap::new().insert(2, 2).insert(1, 1)
+        );
+        assert_ne!(
+            OrdMultiMap::new().insert(1, 1),
+            OrdMultiMap::new().insert(1, 1).insert(1, 1)
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(OrdMultiMap::<(), ()>::new().len(), 0);
+        assert_eq!(OrdMultiMap::new().insert(1, 1).len(), 1);
+        assert_eq!(OrdMultiMap::new().insert(1, 1).insert(1, 2).len(), 2);
+        assert_eq!(OrdMultiMap::new().insert(1, 1).insert(2, 2).len(), 2);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(OrdMultiMap::<(), ()>::new().is_empty());
+        assert!(!OrdMultiMap::new().insert(1, 1).is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let map = OrdMultiMap::from_iter([(1, "foo"), (2, "bar"), (1, "baz")]);
+
+        assert_eq!(map.get(&1).collect::<Vec<_>>(), vec![&"baz", &"foo"]);
+        assert_eq!(map.get(&2).collect::<Vec<_>>(), vec![&"bar"]);
+        assert_eq!(map.get(&3).count(), 0);
+    }
+
+    #[test]
+    fn contains_key() {
+        let map = OrdMultiMap::from_iter([(1, 1), (3, 3)]);
+
+        assert!(map.contains_key(&1));
+        assert!(!map.contains_key(&2));
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(
+            OrdMultiMap::from_iter([(3, 1), (1, 1), (2, 1), (1, 2)])
+                .keys()
+                .collect::<Vec<_>>(),
+            vec![&1, &2, &3]
+        );
+    }
+
+    #[test]
+    fn range() {
+        let map = OrdMultiMap::from_iter([(1, 1), (3, 3), (5, 5), (3, 4)]);
+
+        assert_eq!(
+            map.range(2..5).collect::<Vec<_>>(),
+            vec![(&3, &4), (&3, &3)]
+        );
+        assert_eq!(
+            map.range(3..=5).collect::<Vec<_>>(),
+            vec![(&3, &4), (&3, &3), (&5, &5)]
+        );
+        assert_eq!(map.range(..3).collect::<Vec<_>>(), vec![(&1, &1)]);
+        assert_eq!(map.range(4..).collect::<Vec<_>>(), vec![(&5, &5)]);
+        assert_eq!(map.range(..).count(), 4);
+        assert_eq!(
+            map.range((Bound::Excluded(3), Bound::Unbounded))
+                .collect::<Vec<_>>(),
+            vec![(&5, &5)]
+        );
+        assert_eq!(map.range(6..).count(), 0);
+    }
+
+    #[test]
+    fn remove() {
+        let map = OrdMultiMap::from_iter([(1, 1), (2, 2), (1, 3)]);
+
+        assert_eq!(map.remove(&1), OrdMultiMap::from_iter([(2, 2)]));
+        assert_eq!(map.remove(&1).len(), 1);
+        assert_eq!(map.remove(&3), map);
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            OrdMultiMap::from_iter([(2, 1), (1, 2), (2, 3)])
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![(&1, &2), (&2, &3), (&2, &1)]
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", OrdMultiMap::<(), ()>::new()), "{}");
+        assert_eq!(
+            format!("{:?}", OrdMultiMap::from_iter([(2, 1), (1, 2), (2, 3)])),
+            "{1: [2], 2: [3, 1]}"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let map = OrdMultiMap::from_iter([(1, 1), (2, 2), (1, 3)]);
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            (&map).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{list, List};
+use std::{
+    fmt::{self, Debug, Formatter},
+    iter::Rev,
+    vec,
+};
+
+pub struct RingBuffer<T> {
+    front: List<T>,
+    back: List<T>,
+    capacity: usize,
+}
+
+impl<T> RingBuffer<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            front: Default::default(),
+            back: Default::default(),
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.front
+            .into_iter()
+            .next()
+            .or_else(|| self.back.into_iter().last())
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.back
+            .into_iter()
+            .next()
+            .or_else(|| self.front.into_iter().last())
+    }
+
+    pub fn iter(&self) -> RingBufferIterator<'_, T> {
+        self.into_iter()
+    }
+}
+
+impl<T: Clone> RingBuffer<T> {
+    pub fn push(&self, value: T) -> Self {
+        if self.capacity == 0 {
+            return self.clone();
+        }
+
+        let buffer = if self.is_full() {
+            self.pop()
+        } else {
+            self.clone()
+        };
+
+        Self {
+            front: buffer.front,
+            back: buffer.back.push_front(value),
+            capacity: self.capacity,
+        }
+    }
+
+    pub fn push_iter(&self, iterator: impl IntoIterator<Item = T>) -> Self {
+        let mut buffer = self.clone();
+
+        for value in iterator {
+            buffer = buffer.push(value);
+        }
+
+        buffer
+    }
+
+    pub fn pop(&self) -> Self {
+        if self.front.is_empty() {
+            Self {
+                front: List::new().push_front_iter(self.back.clone()).pop_front(),
+                back: List::new(),
+                capacity: self.capacity,
+            }
+        } else {
+            Self {
+                front: self.front.pop_front(),
+                back: self.back.clone(),
+                capacity: self.capacity,
+            }
+        }
+    }
+}
+
+impl<T> Clone for RingBuffer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front.clone(),
+            back: self.back.clone(),
+            capacity: self.capacity,
+        }
+    }
+}
+
+impl<T: Debug> Debug for RingBuffer<T> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "[")?;
+
+        for (index, value) in self.into_iter().enumerate() {
+            write!(formatter, "{:?}", value)?;
+
+            if index < self.len() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "]")?;
+
+        Ok(())
+    }
+}
+
+impl<T: PartialEq> PartialEq for RingBuffer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.capacity == other.capacity && self.len() == other.len() && self.into_iter().eq(other)
+    }
+}
+
+impl<T: Eq> Eq for RingBuffer<T> {}
+
+pub struct RingBufferIterator<'a, T> {
+    front_iterator: list::ListIterator<'a, T>,
+    back_iterator: Rev<vec::IntoIter<&'a T>>,
+}
+
+impl<'a, T> IntoIterator for &'a RingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = RingBufferIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RingBufferIterator {
+            front_iterator: self.front.into_iter(),
+            back_iterator: self.back.into_iter().collect::<Vec<_>>().into_iter().rev(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for RingBufferIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front_iterator
+            .next()
+            .or_else(|| self.back_iterator.next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        RingBuffer::<()>::new(42);
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(RingBuffer::<()>::new(2), RingBuffer::new(2));
+        assert_ne!(RingBuffer::<()>::new(2), RingBuffer::new(3));
+        assert_ne!(RingBuffer::new(2), RingBuffer::new(2).push(1));
+        assert_eq!(
+            RingBuffer::new(2).push(1).push(2).push(3),
+            RingBuffer::new(2).push(2).push(3)
+        );
+        assert_eq!(
+            RingBuffer::new(3).push_iter([1, 2, 3]).pop(),
+            RingBuffer::new(3).push_iter([2, 3])
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(RingBuffer::<()>::new(2).len(), 0);
+        assert_eq!(RingBuffer::new(2).push(1).len(), 1);
+        assert_eq!(RingBuffer::new(2).push(1).push(2).len(), 2);
+        assert_eq!(RingBuffer::new(2).push(1).push(2).push(3).len(), 2);
+        assert_eq!(RingBuffer::new(0).push(1).len(), 0);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(RingBuffer::<()>::new(2).is_empty());
+        assert!(!RingBuffer::new(2).push(1).is_empty());
+    }
+
+    #[test]
+    fn is_full() {
+        assert!(!RingBuffer::new(2).push(1).is_full());
+        assert!(RingBuffer::new(2).push(1).push(2).is_full());
+    }
+
+    #[test]
+    fn first() {
+        assert_eq!(RingBuffer::<()>::new(2).first(), None);
+        assert_eq!(RingBuffer::new(2).push(1).first(), Some(&1));
+        assert_eq!(RingBuffer::new(2).push_iter([1, 2, 3]).first(), Some(&2));
+        assert_eq!(RingBuffer::new(2).push_iter([1, 2, 3, 4]).first(), Some(&3));
+    }
+
+    #[test]
+    fn last() {
+        assert_eq!(RingBuffer::<()>::new(2).last(), None);
+        assert_eq!(RingBuffer::new(2).push(1).last(), Some(&1));
+        assert_eq!(RingBuffer::new(2).push_iter([1, 2, 3]).last(), Some(&3));
+        assert_eq!(
+            RingBuffer::new(3).push_iter([1, 2, 3]).pop().last(),
+            Some(&3)
+        );
+    }
+
+    #[test]
+    fn pop() {
+        assert_eq!(RingBuffer::<()>::new(2).pop(), RingBuffer::new(2));
+        assert_eq!(RingBuffer::new(2).push(1).pop(), RingBuffer::new(2));
+        assert_eq!(
+            RingBuffer::new(4).push_iter([1, 2, 3]).pop().pop(),
+            RingBuffer::new(4).push(3)
+        );
+    }
+
+    #[test]
+    fn push_persistent() {
+        let buffer = RingBuffer::new(2).push_iter([1, 2]);
+        let other = buffer.push(3);
+
+        assert_eq!(buffer.into_iter().collect::<Vec<_>>(), vec![&1, &2]);
+        assert_eq!(other.into_iter().collect::<Vec<_>>(), vec![&2, &3]);
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            RingBuffer::new(3)
+                .push_iter([1, 2, 3, 4, 5])
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![&3, &4, &5]
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", RingBuffer::<()>::new(2)), "[]");
+        assert_eq!(
+            format!("{:?}", RingBuffer::new(2).push_iter([1, 2, 3])),
+            "[2, 3]"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let buffer = RingBuffer::new(2).push_iter([1, 2, 3]);
+
+        assert_eq!(
+            buffer.iter().collect::<Vec<_>>(),
+            (&buffer).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
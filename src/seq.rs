@@ -0,0 +1,622 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    ops::Index,
+    rc::Rc,
+};
+
+const DELTA: usize = 3;
+const RATIO: usize = 2;
+
+pub struct Seq<T>(Tree<T>);
+
+type Tree<T> = Option<Rc<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    left: Tree<T>,
+    right: Tree<T>,
+    size: usize,
+}
+
+impl<T> Seq<T> {
+    pub fn new() -> Self {
+        Self(None)
+    }
+
+    pub fn len(&self) -> usize {
+        size(&self.0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        get(&self.0, index)
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    pub fn iter(&self) -> SeqIterator<'_, T> {
+        self.into_iter()
+    }
+}
+
+impl<T: Clone> Seq<T> {
+    pub fn push_front(&self, value: T) -> Self {
+        self.insert(0, value)
+    }
+
+    pub fn push_back(&self, value: T) -> Self {
+        self.insert(self.len(), value)
+    }
+
+    pub fn insert(&self, index: usize, value: T) -> Self {
+        assert!(index <= self.len(), "insertion index out of bounds");
+
+        Self(insert(&self.0, index, value))
+    }
+
+    pub fn remove(&self, index: usize) -> Self {
+        assert!(index < self.len(), "removal index out of bounds");
+
+        Self(remove(&self.0, index))
+    }
+
+    pub fn set(&self, index: usize, value: T) -> Self {
+        assert!(index < self.len(), "index out of bounds");
+
+        Self(set(&self.0, index, value))
+    }
+
+    pub fn split_at(&self, index: usize) -> (Self, Self) {
+        assert!(index <= self.len(), "split index out of bounds");
+
+        let (left, right) = split(&self.0, index);
+
+        (Self(left), Self(right))
+    }
+
+    pub fn concat(&self, other: &Self) -> Self {
+        Self(merge(&self.0, &other.0))
+    }
+}
+
+fn size<T>(tree: &Tree<T>) -> usize {
+    tree.as_ref().map_or(0, |node| node.size)
+}
+
+fn node<T>(left: Tree<T>, value: T, right: Tree<T>) -> Tree<T> {
+    Some(
+        Node {
+            size: size(&left) + size(&right) + 1,
+            value,
+            left,
+            right,
+        }
+        .into(),
+    )
+}
+
+fn balance<T: Clone>(left: Tree<T>, value: T, right: Tree<T>) -> Tree<T> {
+    let left_size = size(&left);
+    let right_size = size(&right);
+
+    if left_size + right_size <= 1 {
+        node(left, value, right)
+    } else if right_size > DELTA * left_size {
+        let right = right.expect("non-empty tree");
+
+        if size(&right.left) < RATIO * size(&right.right) {
+            node(
+                node(left, value, right.left.clone()),
+                right.value.clone(),
+                right.right.clone(),
+            )
+        } else {
+            let middle = right.left.as_ref().expect("non-empty tree");
+
+            node(
+                node(left, value, middle.left.clone()),
+                middle.value.clone(),
+                node(
+                    middle.right.clone(),
+                    right.value.clone(),
+                    right.right.clone(),
+                ),
+            )
+        }
+    } else if left_size > DELTA * right_size {
+        let left = left.expect("non-empty tree");
+
+        if size(&left.right) < RATIO * size(&left.left) {
+            node(
+                left.left.clone(),
+                left.value.clone(),
+                node(left.right.clone(), value, right),
+            )
+        } else {
+            let middle = left.right.as_ref().expect("non-empty tree");
+
+            node(
+                node(left.left.clone(), left.value.clone(), middle.left.clone()),
+                middle.value.clone(),
+                node(middle.right.clone(), value, right),
+            )
+        }
+    } else {
+        node(left, value, right)
+    }
+}
+
+fn insert<T: Clone>(tree: &Tree<T>, index: usize, value: T) -> Tree<T> {
+    if let Some(node) = tree {
+        let left_size = size(&node.left);
+
+        if index <= left_size {
+            balance(
+                insert(&node.left, index, value),
+                node.value.clone(),
+                node.right.clone(),
+            )
+        } else {
+            balance(
+                node.left.clone(),
+                node.value.clone(),
+                insert(&node.right, index - left_size - 1, value),
+            )
+        }
+    } else {
+        node(None, value, None)
+    }
+}
+
+fn remove<T: Clone>(tree: &Tree<T>, index: usize) -> Tree<T> {
+    let node = tree.as_ref().expect("non-empty tree");
+    let left_size = size(&node.left);
+
+    if index < left_size {
+        balance(
+            remove(&node.left, index),
+            node.value.clone(),
+            node.right.clone(),
+        )
+    } else if index == left_size {
+        glue(&node.left, &node.right)
+    } else {
+        balance(
+            node.left.clone(),
+            node.value.clone(),
+            remove(&node.right, index - left_size - 1),
+        )
+    }
+}
+
+fn set<T: Clone>(tree: &Tree<T>, index: usize, value: T) -> Tree<T> {
+    let node = tree.as_ref().expect("non-empty tree");
+    let left_size = size(&node.left);
+
+    if index < left_size {
+        self::node(
+            set(&node.left, index, value),
+            node.value.clone(),
+            node.right.clone(),
+        )
+    } else if index == left_size {
+        self::node(node.left.clone(), value, node.right.clone())
+    } else {
+        self::node(
+            node.left.clone(),
+            node.value.clone(),
+            set(&node.right, index - left_size - 1, value),
+        )
+    }
+}
+
+fn split<T: Clone>(tree: &Tree<T>, index: usize) -> (Tree<T>, Tree<T>) {
+    if let Some(node) = tree {
+        let left_size = size(&node.left);
+
+        if index <= left_size {
+            let (left, right) = split(&node.left, index);
+
+            (left, link(right, node.value.clone(), node.right.clone()))
+        } else {
+            let (left, right) = split(&node.right, index - left_size - 1);
+
+            (link(node.left.clone(), node.value.clone(), left), right)
+        }
+    } else {
+        (None, None)
+    }
+}
+
+fn link<T: Clone>(left: Tree<T>, value: T, right: Tree<T>) -> Tree<T> {
+    match (&left, &right) {
+        (None, _) => insert(&right, 0, value),
+        (_, None) => insert(&left, size(&left), value),
+        (Some(left_node), Some(right_node)) => {
+            if DELTA * left_node.size < right_node.size {
+                balance(
+                    link(left, value, right_node.left.clone()),
+                    right_node.value.clone(),
+                    right_node.right.clone(),
+                )
+            } else if DELTA * right_node.size < left_node.size {
+                balance(
+                    left_node.left.clone(),
+                    left_node.value.clone(),
+                    link(left_node.right.clone(), value, right),
+                )
+            } else {
+                node(left, value, right)
+            }
+        }
+    }
+}
+
+fn merge<T: Clone>(left: &Tree<T>, right: &Tree<T>) -> Tree<T> {
+    match (left, right) {
+        (None, _) => right.clone(),
+        (_, None) => left.clone(),
+        (Some(left_node), Some(right_node)) => {
+            if DELTA * left_node.size < right_node.size {
+                balance(
+                    merge(left, &right_node.left),
+                    right_node.value.clone(),
+                    right_node.right.clone(),
+                )
+            } else if DELTA * right_node.size < left_node.size {
+                balance(
+                    left_node.left.clone(),
+                    left_node.value.clone(),
+                    merge(&left_node.right, right),
+                )
+            } else {
+                glue(left, right)
+            }
+        }
+    }
+}
+
+fn glue<T: Clone>(left: &Tree<T>, right: &Tree<T>) -> Tree<T> {
+    match (left, right) {
+        (None, _) => right.clone(),
+        (_, None) => left.clone(),
+        _ => {
+            if size(left) > size(right) {
+                let last = size(left) - 1;
+                let value = get(left, last).expect("last value").clone();
+
+                balance(remove(left, last), value, right.clone())
+            } else {
+                let value = get(right, 0).expect("first value").clone();
+
+                balance(left.clone(), value, remove(right, 0))
+            }
+        }
+    }
+}
+
+fn get<T>(mut tree: &Tree<T>, mut index: usize) -> Option<&T> {
+    while let Some(node) = tree {
+        let left_size = size(&node.left);
+
+        if index < left_size {
+            tree = &node.left;
+        } else if index == left_size {
+            return Some(&node.value);
+        } else {
+            index -= left_size + 1;
+            tree = &node.right;
+        }
+    }
+
+    None
+}
+
+fn build<T>(iterator: &mut impl Iterator<Item = T>, len: usize) -> Tree<T> {
+    if len == 0 {
+        None
+    } else {
+        let left = build(iterator, len / 2);
+        let value = iterator.next().expect("enough values");
+        let right = build(iterator, len - len / 2 - 1);
+
+        node(left, value, right)
+    }
+}
+
+impl<T> Index<usize> for Seq<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index in bounds")
+    }
+}
+
+impl<T> Clone for Seq<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Default for Seq<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for Seq<T> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "[")?;
+
+        for (index, value) in self.into_iter().enumerate() {
+            write!(formatter, "{:?}", value)?;
+
+            if index < self.len() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "]")?;
+
+        Ok(())
+    }
+}
+
+impl<T: PartialEq> PartialEq for Seq<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.into_iter().eq(other)
+    }
+}
+
+impl<T: Eq> Eq for Seq<T> {}
+
+impl<T> FromIterator<T> for Seq<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let values = iterator.into_iter().collect::<Vec<_>>();
+        let len = values.len();
+
+        Self(build(&mut values.into_iter(), len))
+    }
+}
+
+pub struct SeqIterator<'a, T>(Vec<&'a Node<T>>);
+
+impl<'a, T> SeqIterator<'a, T> {
+    fn push_left(&mut self, mut tree: &'a Tree<T>) {
+        while let Some(node) = tree {
+            self.0.push(node);
+            tree = &node.left;
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Seq<T> {
+    type Item = &'a T;
+    type IntoIter = SeqIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut iterator = SeqIterator(vec![]);
+        iterator.push_left(&self.0);
+        iterator
+    }
+}
+
+impl<'a, T> Iterator for SeqIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.0.pop()?;
+
+        self.push_left(&node.right);
+
+        Some(&node.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_balanced<T>(tree: &Tree<T>) -> bool {
+        if let Some(node) = tree {
+            let left_size = size(&node.left);
+            let right_size = size(&node.right);
+
+            node.size == left_size + right_size + 1
+                && (left_size + right_size <= 1
+                    || (left_size <= DELTA * right_size && right_size <= DELTA * left_size))
+                && is_balanced(&node.left)
+                && is_balanced(&node.right)
+        } else {
+            true
+        }
+    }
+
+    fn random(seed: &mut usize) -> usize {
+        *seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        *seed >> 33
+    }
+
+    fn to_vec<T: Clone>(seq: &Seq<T>) -> Vec<T> {
+        seq.into_iter().cloned().collect()
+    }
+
+    #[test]
+    fn new() {
+        Seq::<()>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(Seq::<()>::new(), Seq::new());
+        assert_ne!(Seq::new(), Seq::new().push_back(1));
+        assert_eq!(Seq::new().push_back(1).push_back(2), Seq::from_iter([1, 2]));
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Seq::<()>::new().len(), 0);
+        assert_eq!(Seq::new().push_back(1).len(), 1);
+        assert_eq!(Seq::from_iter(0..100).len(), 100);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(Seq::<()>::new().is_empty());
+        assert!(!Seq::new().push_back(1).is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let seq = Seq::from_iter(0..100);
+
+        for index in 0..100 {
+            assert_eq!(seq.get(index), Some(&index));
+        }
+
+        assert_eq!(seq.get(100), None);
+        assert_eq!(seq[42], 42);
+    }
+
+    #[test]
+    fn first_and_last() {
+        assert_eq!(Seq::<()>::new().first(), None);
+        assert_eq!(Seq::<()>::new().last(), None);
+        assert_eq!(Seq::from_iter([1, 2, 3]).first(), Some(&1));
+        assert_eq!(Seq::from_iter([1, 2, 3]).last(), Some(&3));
+    }
+
+    #[test]
+    fn push() {
+        let seq = (0..100).fold(Seq::new(), |seq, value| seq.push_back(value));
+
+        assert_eq!(to_vec(&seq), (0..100).collect::<Vec<_>>());
+        assert!(is_balanced(&seq.0));
+
+        let seq = (0..100).fold(Seq::new(), |seq, value| seq.push_front(value));
+
+        assert_eq!(to_vec(&seq), (0..100).rev().collect::<Vec<_>>());
+        assert!(is_balanced(&seq.0));
+    }
+
+    #[test]
+    fn insert() {
+        assert_eq!(
+            Seq::from_iter([1, 3]).insert(1, 2),
+            Seq::from_iter([1, 2, 3])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_out_of_bounds() {
+        Seq::from_iter([1]).insert(2, 2);
+    }
+
+    #[test]
+    fn remove() {
+        assert_eq!(Seq::from_iter([1, 2, 3]).remove(1), Seq::from_iter([1, 3]));
+    }
+
+    #[test]
+    fn set() {
+        let seq = Seq::from_iter([1, 2, 3]);
+
+        assert_eq!(seq.set(1, 4), Seq::from_iter([1, 4, 3]));
+        assert_eq!(seq, Seq::from_iter([1, 2, 3]));
+    }
+
+    #[test]
+    fn split_at() {
+        for len in 0..30 {
+            let seq = Seq::from_iter(0..len);
+
+            for index in 0..=len {
+                let (left, right) = seq.split_at(index);
+
+                assert_eq!(to_vec(&left), (0..index).collect::<Vec<_>>());
+                assert_eq!(to_vec(&right), (index..len).collect::<Vec<_>>());
+                assert!(is_balanced(&left.0));
+                assert!(is_balanced(&right.0));
+            }
+        }
+    }
+
+    #[test]
+    fn concat() {
+        for left in 0..30 {
+            for right in [0, 1, 2, 5, 10, 50, 100] {
+                let seq = Seq::from_iter(0..left).concat(&Seq::from_iter(left..left + right));
+
+                assert_eq!(to_vec(&seq), (0..left + right).collect::<Vec<_>>());
+                assert!(is_balanced(&seq.0));
+            }
+        }
+    }
+
+    #[test]
+    fn random_operations() {
+        let mut seed = 42;
+        let mut seq = Seq::new();
+        let mut vector = vec![];
+
+        for value in 0..2000 {
+            match random(&mut seed) % 5 {
+                0 | 1 => {
+                    let index = random(&mut seed) % (vector.len() + 1);
+
+                    seq = seq.insert(index, value);
+                    vector.insert(index, value);
+                }
+                2 if !vector.is_empty() => {
+                    let index = random(&mut seed) % vector.len();
+
+                    seq = seq.remove(index);
+                    vector.remove(index);
+                }
+                3 => {
+                    let index = random(&mut seed) % (vector.len() + 1);
+                    let (left, right) = seq.split_at(index);
+
+                    seq = right.concat(&left);
+                    vector.rotate_left(index);
+                }
+                _ => {
+                    seq = seq.push_back(value);
+                    vector.push(value);
+                }
+            }
+
+            assert!(is_balanced(&seq.0));
+        }
+
+        assert_eq!(to_vec(&seq), vector);
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", Seq::<()>::new()), "[]");
+        assert_eq!(format!("{:?}", Seq::from_iter([1, 2, 3])), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn iter() {
+        let seq = Seq::from_iter([1, 2, 3]);
+
+        assert_eq!(
+            seq.iter().collect::<Vec<_>>(),
+            (&seq).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
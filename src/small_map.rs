@@ -0,0 +1,387 @@
+use crate::{map, Map};
+use std::{
+    array,
+    borrow::Borrow,
+    fmt::{self, Debug, Formatter},
+    hash::Hash,
+    ops::Index,
+    slice,
+};
+
+pub struct SmallMap<K, V, const N: usize>(Entries<K, V, N>);
+
+enum Entries<K, V, const N: usize> {
+    Inline {
+        entries: [Option<(K, V)>; N],
+        len: usize,
+    },
+    Map(Map<K, V>),
+}
+
+impl<K, V, const N: usize> SmallMap<K, V, N> {
+    pub fn new() -> Self {
+        Self(Entries::Inline {
+            entries: array::from_fn(|_| None),
+            len: 0,
+        })
+    }
+
+    pub fn get<Q: Eq + ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        match &self.0 {
+            Entries::Inline { entries, len } => {
+                entries[..*len].iter().find_map(|entry| match entry {
+                    Some((other_key, value)) if other_key.borrow() == key => Some(value),
+                    _ => None,
+                })
+            }
+            Entries::Map(map) => map.get(key),
+        }
+    }
+
+    pub fn spilled(&self) -> bool {
+        matches!(self.0, Entries::Map(_))
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone, const N: usize> SmallMap<K, V, N> {
+    pub fn insert(&self, key: K, value: V) -> Self {
+        match &self.0 {
+            Entries::Inline { entries, len } => {
+                let index = entries[..*len]
+                    .iter()
+                    .position(|entry| matches!(entry, Some((other, _)) if other == &key));
+
+                if let Some(index) = index {
+                    let mut entries = entries.clone();
+                    entries[index] = Some((key, value));
+
+                    Self(Entries::Inline { entries, len: *len })
+                } else if *len < N {
+                    let mut entries = entries.clone();
+                    entries[*len] = Some((key, value));
+
+                    Self(Entries::Inline {
+                        entries,
+                        len: len + 1,
+                    })
+                } else {
+                    Self(Entries::Map(
+                        entries
+                            .iter()
+                            .flatten()
+                            .cloned()
+                            .collect::<Map<_, _>>()
+                            .insert(key, value),
+                    ))
+                }
+            }
+            Entries::Map(map) => Self(Entries::Map(map.insert(key, value))),
+        }
+    }
+
+    pub fn insert_iter(&self, iterator: impl IntoIterator<Item = (K, V)>) -> Self {
+        let mut map = self.clone();
+
+        for (key, value) in iterator {
+            map = map.insert(key, value);
+        }
+
+        map
+    }
+}
+
+impl<K: Eq + Hash, V, const N: usize> SmallMap<K, V, N> {
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            Entries::Inline { len, .. } => *len,
+            Entries::Map(map) => map.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match &self.0 {
+            Entries::Inline { len, .. } => *len == 0,
+            Entries::Map(map) => map.is_empty(),
+        }
+    }
+
+    pub fn contains_key<Q: Eq + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.get(key).is_some()
+    }
+
+    pub fn iter(&self) -> SmallMapIterator<'_, K, V> {
+        self.into_iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.into_iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.into_iter().map(|(_, value)| value)
+    }
+}
+
+impl<Q: Eq + ?Sized, K: Eq, V, const N: usize> Index<&Q> for SmallMap<K, V, N>
+where
+    K: Borrow<Q>,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("existent key")
+    }
+}
+
+impl<K: Clone, V: Clone, const N: usize> Clone for SmallMap<K, V, N> {
+    fn clone(&self) -> Self {
+        Self(match &self.0 {
+            Entries::Inline { entries, len } => Entries::Inline {
+                entries: entries.clone(),
+                len: *len,
+            },
+            Entries::Map(map) => Entries::Map(map.clone()),
+        })
+    }
+}
+
+impl<K, V, const N: usize> Default for SmallMap<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug + Eq + Hash, V: Debug, const N: usize> Debug for SmallMap<K, V, N> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{{")?;
+
+        for (index, (key, value)) in self.into_iter().enumerate() {
+            write!(formatter, "{:?}: {:?}", key, value)?;
+
+            if index < self.len() - 1 {
+                write!(formatter, ", ")?;
+            }
+        }
+
+        write!(formatter, "}}")?;
+
+        Ok(())
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq, const N: usize> PartialEq for SmallMap<K, V, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && other
+                .into_iter()
+                .all(|(key, value)| self.get(key) == Some(value))
+    }
+}
+
+impl<K: Eq + Hash, V: Eq, const N: usize> Eq for SmallMap<K, V, N> {}
+
+impl<K: Clone + Eq + Hash, V: Clone, const N: usize> FromIterator<(K, V)> for SmallMap<K, V, N> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iterator: I) -> Self {
+        Self::new().insert_iter(iterator)
+    }
+}
+
+pub struct SmallMapIterator<'a, K: Eq + Hash, V> {
+    inline_iterator: slice::Iter<'a, Option<(K, V)>>,
+    map_iterator: Option<map::MapIterator<'a, K, V>>,
+}
+
+impl<'a, K: Eq + Hash, V, const N: usize> IntoIterator for &'a SmallMap<K, V, N> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = SmallMapIterator<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match &self.0 {
+            Entries::Inline { entries, len } => SmallMapIterator {
+                inline_iterator: entries[..*len].iter(),
+                map_iterator: None,
+            },
+            Entries::Map(map) => SmallMapIterator {
+                inline_iterator: Default::default(),
+                map_iterator: Some(map.into_iter()),
+            },
+        }
+    }
+}
+
+impl<'a, K: Eq + Hash, V> Iterator for SmallMapIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(entry) = self.inline_iterator.next() {
+            entry.as_ref().map(|(key, value)| (key, value))
+        } else {
+            self.map_iterator.as_mut()?.next()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn new() {
+        SmallMap::<(), (), 4>::new();
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(SmallMap::<(), (), 4>::new(), SmallMap::new());
+        assert_ne!(SmallMap::<_, _, 4>::new(), SmallMap::new().insert(1, 1));
+        assert_eq!(
+            SmallMap::<_, _, 4>::new().insert(1, 1),
+            SmallMap::new().insert(1, 1).insert(1, 1)
+        );
+        assert_eq!(
+            SmallMap::<_, _, 4>::new().insert(2, 2).insert(1, 1),
+            SmallMap::new().insert(1, 1).insert(2, 2)
+        );
+        assert_eq!(
+            SmallMap::<_, _, 1>::new().insert(2, 2).insert(1, 1),
+            SmallMap::new().insert(1, 1).insert(2, 2)
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(SmallMap::<(), (), 4>::new().len(), 0);
+        assert_eq!(SmallMap::<_, _, 4>::new().insert(1, 1).len(), 1);
+        assert_eq!(
+            SmallMap::<_, _, 4>::new().insert(1, 1).insert(1, 1).len(),
+            1
+        );
+        assert_eq!(
+            SmallMap::<_, _, 4>::new().insert(1, 1).insert(2, 2).len(),
+            2
+        );
+        assert_eq!(
+            SmallMap::<_, _, 1>::new().insert(1, 1).insert(2, 2).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(SmallMap::<(), (), 4>::new().is_empty());
+        assert!(!SmallMap::<_, _, 4>::new().insert(1, 1).is_empty());
+    }
+
+    #[test]
+    fn get() {
+        let map = SmallMap::<_, _, 4>::new().insert(1, 2).insert(3, 4);
+
+        assert_eq!(map.get(&1), Some(&2));
+        assert_eq!(map.get(&3), Some(&4));
+        assert_eq!(map.get(&4), None);
+    }
+
+    #[test]
+    fn get_spilled() {
+        let map = SmallMap::<_, _, 1>::new().insert(1, 2).insert(3, 4);
+
+        assert_eq!(map.get(&1), Some(&2));
+        assert_eq!(map.get(&3), Some(&4));
+        assert_eq!(map.get(&4), None);
+    }
+
+    #[test]
+    fn insert_persistent() {
+        let map = SmallMap::<_, _, 4>::new().insert(1, 1);
+        let other = map.insert(1, 2);
+
+        assert_eq!(map.get(&1), Some(&1));
+        assert_eq!(other.get(&1), Some(&2));
+    }
+
+    #[test]
+    fn spilled() {
+        assert!(!SmallMap::<_, _, 2>::new().insert(1, 1).spilled());
+        assert!(!SmallMap::<_, _, 2>::new()
+            .insert(1, 1)
+            .insert(2, 2)
+            .spilled());
+        assert!(!SmallMap::<_, _, 2>::new()
+            .insert(1, 1)
+            .insert(2, 2)
+            .insert(2, 3)
+            .spilled());
+        assert!(SmallMap::<_, _, 2>::new()
+            .insert(1, 1)
+            .insert(2, 2)
+            .insert(3, 3)
+            .spilled());
+    }
+
+    #[test]
+    fn contains_key() {
+        assert!(SmallMap::<_, _, 4>::new()
+            .insert(1, 1)
+            .insert(2, 2)
+            .contains_key(&2));
+        assert!(!SmallMap::<_, _, 4>::new().insert(1, 1).contains_key(&2));
+    }
+
+    #[test]
+    fn into_iter() {
+        assert_eq!(
+            SmallMap::<_, _, 4>::new()
+                .insert(1, 1)
+                .insert(2, 2)
+                .into_iter()
+                .collect::<HashSet<_>>(),
+            [(&1, &1), (&2, &2)].into_iter().collect()
+        );
+        assert_eq!(
+            SmallMap::<_, _, 1>::new()
+                .insert(1, 1)
+                .insert(2, 2)
+                .into_iter()
+                .collect::<HashSet<_>>(),
+            [(&1, &1), (&2, &2)].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn from_iter() {
+        assert_eq!(
+            SmallMap::<_, _, 4>::from_iter([(1, 1), (2, 2), (1, 1)]),
+            SmallMap::new().insert(1, 1).insert(2, 2),
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", SmallMap::<(), (), 4>::new()), "{}");
+        assert_eq!(
+            format!("{:?}", SmallMap::<_, _, 4>::new().insert(1, 2)),
+            "{1: 2}"
+        );
+        assert_eq!(
+            format!("{:?}", SmallMap::<_, _, 4>::from_iter([(1, 2), (3, 4)])),
+            "{1: 2, 3: 4}"
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let map = SmallMap::<_, _, 1>::new().insert(1, 1).insert(2, 2);
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            (&map).into_iter().collect::<Vec<_>>()
+        );
+    }
+}
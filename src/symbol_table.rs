@@ -0,0 +1,209 @@
+use crate::{FlailMap, List};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+};
+
+pub struct SymbolTable<V> {
+    symbols: FlailMap<String, (usize, V)>,
+    scopes: List<FlailMap<String, (usize, V)>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DuplicateDefinitionError {
+    name: String,
+}
+
+impl<V> SymbolTable<V> {
+    pub fn new(globals: HashMap<String, V>) -> Self {
+        Self {
+            symbols: FlailMap::new(
+                globals
+                    .into_iter()
+                    .map(|(name, value)| (name, (0, value)))
+                    .collect(),
+            ),
+            scopes: List::new(),
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.scopes.len()
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<(usize, &V)> {
+        self.symbols.get(name).map(|(depth, value)| (*depth, value))
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.lookup(name).is_some()
+    }
+
+    pub fn define(
+        &self,
+        name: impl Into<String>,
+        value: V,
+    ) -> Result<Self, DuplicateDefinitionError> {
+        let name = name.into();
+
+        if matches!(self.lookup(&name), Some((depth, _)) if depth == self.depth()) {
+            return Err(DuplicateDefinitionError { name });
+        }
+
+        Ok(Self {
+            symbols: self.symbols.insert(name, (self.depth(), value)),
+            scopes: self.scopes.clone(),
+        })
+    }
+
+    pub fn enter_scope(&self) -> Self {
+        Self {
+            symbols: self.symbols.clone(),
+            scopes: self.scopes.push_front(self.symbols.clone()),
+        }
+    }
+
+    pub fn exit_scope(&self) -> Option<Self> {
+        Some(Self {
+            symbols: self.scopes.first()?.clone(),
+            scopes: self.scopes.pop_front(),
+        })
+    }
+}
+
+impl DuplicateDefinitionError {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for DuplicateDefinitionError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "duplicate definition of {}", self.name)
+    }
+}
+
+impl Error for DuplicateDefinitionError {}
+
+impl<V> Clone for SymbolTable<V> {
+    fn clone(&self) -> Self {
+        Self {
+            symbols: self.symbols.clone(),
+            scopes: self.scopes.clone(),
+        }
+    }
+}
+
+impl<V> Default for SymbolTable<V> {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<V: Debug> Debug for SymbolTable<V> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("SymbolTable")
+            .field("depth", &self.depth())
+            .field("symbols", &self.symbols)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        SymbolTable::<()>::new(Default::default());
+    }
+
+    #[test]
+    fn lookup_global() {
+        let table = SymbolTable::new([("foo".into(), 1)].into_iter().collect());
+
+        assert_eq!(table.lookup("foo"), Some((0, &1)));
+        assert_eq!(table.lookup("bar"), None);
+        assert!(table.contains("foo"));
+        assert!(!table.contains("bar"));
+    }
+
+    #[test]
+    fn define() {
+        let table = SymbolTable::default().define("foo", 1).unwrap();
+
+        assert_eq!(table.lookup("foo"), Some((0, &1)));
+    }
+
+    #[test]
+    fn define_duplicate() {
+        let table = SymbolTable::default().define("foo", 1).unwrap();
+
+        assert_eq!(
+            table.define("foo", 2).unwrap_err(),
+            DuplicateDefinitionError { name: "foo".into() }
+        );
+        assert_eq!(table.define("foo", 2).unwrap_err().name(), "foo");
+        assert_eq!(
+            table.define("foo", 2).unwrap_err().to_string(),
+            "duplicate definition of foo"
+        );
+    }
+
+    #[test]
+    fn define_duplicate_global() {
+        let table = SymbolTable::new([("foo".into(), 1)].into_iter().collect());
+
+        assert!(table.define("foo", 2).is_err());
+        assert!(table.enter_scope().define("foo", 2).is_ok());
+    }
+
+    #[test]
+    fn enter_scope() {
+        let table = SymbolTable::default()
+            .define("foo", 1)
+            .unwrap()
+            .enter_scope()
+            .define("foo", 2)
+            .unwrap()
+            .define("bar", 3)
+            .unwrap();
+
+        assert_eq!(table.depth(), 1);
+        assert_eq!(table.lookup("foo"), Some((1, &2)));
+        assert_eq!(table.lookup("bar"), Some((1, &3)));
+    }
+
+    #[test]
+    fn lookup_outer_scope() {
+        let table = SymbolTable::default()
+            .define("foo", 1)
+            .unwrap()
+            .enter_scope()
+            .enter_scope();
+
+        assert_eq!(table.depth(), 2);
+        assert_eq!(table.lookup("foo"), Some((0, &1)));
+    }
+
+    #[test]
+    fn exit_scope() {
+        let table = SymbolTable::default()
+            .define("foo", 1)
+            .unwrap()
+            .enter_scope()
+            .define("foo", 2)
+            .unwrap()
+            .define("bar", 3)
+            .unwrap()
+            .exit_scope()
+            .unwrap();
+
+        assert_eq!(table.depth(), 0);
+        assert_eq!(table.lookup("foo"), Some((0, &1)));
+        assert_eq!(table.lookup("bar"), None);
+        assert!(table.exit_scope().is_none());
+    }
+}